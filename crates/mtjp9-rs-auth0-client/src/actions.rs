@@ -0,0 +1,45 @@
+//! Actions functionality for Auth0
+//!
+//! This module provides functions for managing, testing, deploying and binding Actions
+//! through the Auth0 Management API v2.
+
+mod create_action;
+mod delete_action;
+mod deploy_action;
+mod events;
+mod get_action;
+mod get_execution;
+mod list_actions;
+mod test_action;
+mod triggers;
+mod update_action;
+mod versions;
+
+pub use create_action::{
+    create_action, Action, ActionBuildStatus, ActionDependency, ActionRuntime, ActionSecret,
+    ActionTrigger, CreateActionRequest, TriggerId,
+};
+pub use delete_action::delete_action;
+pub use deploy_action::{deploy_action, ActionVersion};
+pub use events::{
+    ActionCommand, ActionEvent, AuthenticationMethod, CredentialsExchangeEvent,
+    EventAuthentication, EventAuthorization, EventClient, EventConnection, EventGeoIp,
+    EventRequest, EventResourceServer, EventStats, EventTenant, EventTransaction, EventUser,
+    PostLoginEvent, TriggerEvent,
+};
+pub use get_action::get_action;
+pub use get_execution::{
+    get_execution, Execution, ExecutionError, ExecutionResult, ExecutionStatus,
+};
+pub use list_actions::{list_actions, ListActionsQuery, ListActionsResponse};
+pub use test_action::{test_action, TestActionResponse};
+pub use triggers::{
+    get_trigger_bindings, list_triggers, update_trigger_bindings, ActionRef,
+    ListTriggerBindingsQuery, ListTriggerBindingsResponse, Trigger, TriggerBinding,
+    TriggerBindingUpdate,
+};
+pub use update_action::{update_action, UpdateActionRequest};
+pub use versions::{
+    get_action_version, list_action_versions, rollback_action, ListActionVersionsQuery,
+    ListActionVersionsResponse,
+};
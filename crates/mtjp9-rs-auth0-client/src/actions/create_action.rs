@@ -0,0 +1,493 @@
+//! Action creation for Auth0 Management API v2
+//!
+//! This module provides the `create_action` function for creating an Action, along with
+//! the typed Action model. It wraps the POST /api/v2/actions/actions endpoint.
+//!
+//! A new Action is built asynchronously and only runs once it has been deployed with
+//! `deploy_action` and bound to its trigger with `update_trigger_bindings`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     actions::{ActionDependency, ActionRuntime, ActionSecret, ActionTrigger, CreateActionRequest, TriggerId, create_action},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Source checked into the repository
+//!     let code = "exports.onExecutePostLogin = async (event, api) => {};";
+//!
+//!     let request = CreateActionRequest {
+//!         runtime: Some(ActionRuntime::Node22),
+//!         dependencies: Some(vec![ActionDependency::new("axios", "1.7.2")]),
+//!         secrets: Some(vec![ActionSecret::new("API_KEY", "s3cr3t")]),
+//!         ..CreateActionRequest::new("add-roles", ActionTrigger::new(TriggerId::PostLogin, "v3"), code)
+//!     };
+//!
+//!     let action = create_action(&domain, &token, request).await?;
+//!     println!("Created action {}", action.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/post-action)
+//! for more details about the create action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+use super::deploy_action::ActionVersion;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A point in an Auth0 flow where Actions run
+///
+/// Trigger IDs not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TriggerId {
+    /// `post-login`: after a user logged in
+    PostLogin,
+    /// `credentials-exchange`: before an access token is issued for client credentials
+    CredentialsExchange,
+    /// `pre-user-registration`: before a user is created in a database connection
+    PreUserRegistration,
+    /// `post-user-registration`: after a user was created in a database connection
+    PostUserRegistration,
+    /// `post-change-password`: after a user changed their password
+    PostChangePassword,
+    /// `send-phone-message`: to send MFA text messages through a custom provider
+    SendPhoneMessage,
+    /// `password-reset-post-challenge`: after the challenge of a password reset
+    PasswordResetPostChallenge,
+    /// Any other trigger
+    Other(String),
+}
+
+impl TriggerId {
+    /// The ID Auth0 uses for this trigger.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PostLogin => "post-login",
+            Self::CredentialsExchange => "credentials-exchange",
+            Self::PreUserRegistration => "pre-user-registration",
+            Self::PostUserRegistration => "post-user-registration",
+            Self::PostChangePassword => "post-change-password",
+            Self::SendPhoneMessage => "send-phone-message",
+            Self::PasswordResetPostChallenge => "password-reset-post-challenge",
+            Self::Other(id) => id,
+        }
+    }
+}
+
+impl From<String> for TriggerId {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "post-login" => Self::PostLogin,
+            "credentials-exchange" => Self::CredentialsExchange,
+            "pre-user-registration" => Self::PreUserRegistration,
+            "post-user-registration" => Self::PostUserRegistration,
+            "post-change-password" => Self::PostChangePassword,
+            "send-phone-message" => Self::SendPhoneMessage,
+            "password-reset-post-challenge" => Self::PasswordResetPostChallenge,
+            _ => Self::Other(id),
+        }
+    }
+}
+
+impl From<TriggerId> for String {
+    fn from(id: TriggerId) -> Self {
+        match id {
+            TriggerId::Other(id) => id,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TriggerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Node.js runtime an Action runs on
+///
+/// Runtimes not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ActionRuntime {
+    Node18,
+    Node22,
+    Other(String),
+}
+
+impl ActionRuntime {
+    /// The name Auth0 uses for this runtime.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Node18 => "node18",
+            Self::Node22 => "node22",
+            Self::Other(runtime) => runtime,
+        }
+    }
+}
+
+impl From<String> for ActionRuntime {
+    fn from(runtime: String) -> Self {
+        match runtime.as_str() {
+            "node18" => Self::Node18,
+            "node22" => Self::Node22,
+            _ => Self::Other(runtime),
+        }
+    }
+}
+
+impl From<ActionRuntime> for String {
+    fn from(runtime: ActionRuntime) -> Self {
+        match runtime {
+            ActionRuntime::Other(runtime) => runtime,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// A trigger an Action supports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionTrigger {
+    pub id: TriggerId,
+
+    /// Version of the trigger (e.g. "v3" for `post-login`)
+    pub version: String,
+}
+
+impl ActionTrigger {
+    pub fn new(id: TriggerId, version: impl Into<String>) -> Self {
+        Self {
+            id,
+            version: version.into(),
+        }
+    }
+}
+
+/// An npm package an Action depends on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionDependency {
+    pub name: String,
+    pub version: String,
+}
+
+impl ActionDependency {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+        }
+    }
+}
+
+/// A secret available to an Action as `event.secrets`
+///
+/// Auth0 never returns secret values; secrets read from the API only have a name.
+/// `Debug` output redacts the value.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSecret {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// When the secret was last changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl fmt::Debug for ActionSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionSecret")
+            .field("name", &self.name)
+            .field("value", &self.value.as_ref().map(|_| "[REDACTED]"))
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+impl ActionSecret {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: Some(value.into()),
+            updated_at: None,
+        }
+    }
+}
+
+/// Build status of an Action or Action version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionBuildStatus {
+    Pending,
+    Building,
+    Packaged,
+    Built,
+    Retrying,
+    Failed,
+    #[serde(other)]
+    Other,
+}
+
+/// Request body for creating an Action
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateActionRequest {
+    /// The name of the Action
+    pub name: String,
+
+    /// The triggers the Action can be bound to
+    pub supported_triggers: Vec<ActionTrigger>,
+
+    /// The source code of the Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// The Node.js runtime. Defaults to the trigger's default runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<ActionRuntime>,
+
+    /// npm packages the Action depends on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<ActionDependency>>,
+
+    /// Secrets available to the Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionSecret>>,
+}
+
+impl CreateActionRequest {
+    pub fn new(name: impl Into<String>, trigger: ActionTrigger, code: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            supported_triggers: vec![trigger],
+            code: Some(code.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// An Action as returned by the Management API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Action {
+    /// The Action's unique identifier
+    pub id: String,
+
+    /// The name of the Action
+    pub name: String,
+
+    /// The triggers the Action can be bound to
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+
+    /// The current, not necessarily deployed, source code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<ActionRuntime>,
+
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+
+    /// Names of the Action's secrets
+    #[serde(default)]
+    pub secrets: Vec<ActionSecret>,
+
+    /// Build status of the current code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ActionBuildStatus>,
+
+    /// Whether the current code has been deployed
+    #[serde(default)]
+    pub all_changes_deployed: bool,
+
+    /// The version that currently runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployed_version: Option<ActionVersion>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Creates an Action.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:actions` scope
+/// * `request` - The Action's code and configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The name is empty or no trigger is given
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn create_action(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateActionRequest,
+) -> Result<Action> {
+    // Validate name and triggers
+    if request.name.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action name cannot be empty".to_string(),
+        ));
+    }
+    if request.supported_triggers.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action must support at least one trigger".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/actions/actions");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the Action
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => {
+            response.json::<Action>().await.map_err(Auth0Error::from)
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_create_action_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateActionRequest {
+            runtime: Some(ActionRuntime::Node22),
+            dependencies: Some(vec![ActionDependency::new("axios", "1.7.2")]),
+            secrets: Some(vec![ActionSecret::new("API_KEY", "s3cr3t")]),
+            ..CreateActionRequest::new(
+                "add-roles",
+                ActionTrigger::new(TriggerId::PostLogin, "v3"),
+                "exports.onExecutePostLogin = async () => {};",
+            )
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/actions/actions")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{
+                    "name": "add-roles",
+                    "supported_triggers": [{"id": "post-login", "version": "v3"}],
+                    "code": "exports.onExecutePostLogin = async () => {};",
+                    "runtime": "node22",
+                    "dependencies": [{"name": "axios", "version": "1.7.2"}],
+                    "secrets": [{"name": "API_KEY", "value": "s3cr3t"}]
+                }"#
+                .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "act_1",
+                    "name": "add-roles",
+                    "supported_triggers": [{"id": "post-login", "version": "v3"}],
+                    "runtime": "node22",
+                    "secrets": [{"name": "API_KEY", "updated_at": "2025-08-06T00:00:00.000Z"}],
+                    "status": "building",
+                    "all_changes_deployed": false
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = create_action(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let action = result.unwrap();
+        assert_eq!(action.status, Some(ActionBuildStatus::Building));
+        assert_eq!(action.supported_triggers[0].id, TriggerId::PostLogin);
+        assert_eq!(action.secrets[0].value, None);
+    }
+
+    #[test]
+    fn test_unknown_trigger_and_runtime() {
+        let trigger: ActionTrigger =
+            serde_json::from_str(r#"{"id": "custom-email-provider", "version": "v1"}"#).unwrap();
+        assert_eq!(
+            trigger.id,
+            TriggerId::Other("custom-email-provider".to_string())
+        );
+
+        let runtime: ActionRuntime = serde_json::from_str(r#""node24""#).unwrap();
+        assert_eq!(serde_json::to_string(&runtime).unwrap(), r#""node24""#);
+    }
+
+    #[tokio::test]
+    async fn test_create_action_without_trigger() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateActionRequest {
+            name: "no-trigger".to_string(),
+            ..Default::default()
+        };
+        let result = create_action(&domain, &token, request).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_action_secret_debug_redacts_value() {
+        let secret = ActionSecret::new("API_KEY", "sk_live_123");
+
+        let output = format!("{secret:?}");
+        assert!(output.contains("API_KEY"));
+        assert!(!output.contains("sk_live_123"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Action deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_action` function for deleting an Action. It wraps the
+//! DELETE /api/v2/actions/actions/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/delete-action)
+//! for more details about the delete action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes an Action.
+///
+/// Auth0 refuses to delete an Action that is bound to a trigger unless `force` is set, in
+/// which case the bindings are removed as well.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:actions` scope
+/// * `action_id` - The ID of the Action to delete
+/// * `force` - Whether to also remove the Action's trigger bindings
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The Action ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_action(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    force: bool,
+) -> Result<()> {
+    // Validate Action ID
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the Action
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&[("force", force)])
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_delete_action_force() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/actions/actions/act_1")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::UrlEncoded("force".into(), "true".into()))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = delete_action(&domain, &token, "act_1", true).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_action_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = delete_action(&domain, &token, "", false).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
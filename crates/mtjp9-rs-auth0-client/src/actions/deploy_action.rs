@@ -0,0 +1,165 @@
+//! Action deployment for Auth0 Management API v2
+//!
+//! This module provides the `deploy_action` function for deploying the current code of an
+//! Action as a new version. It wraps the POST /api/v2/actions/actions/{id}/deploy endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/post-deploy-action)
+//! for more details about the deploy action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_action::{
+    ActionBuildStatus, ActionDependency, ActionRuntime, ActionSecret, ActionTrigger,
+};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An immutable, deployable version of an Action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionVersion {
+    /// The version's unique identifier
+    pub id: String,
+
+    /// ID of the Action the version belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+
+    /// Sequential number of the version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+
+    /// Whether this version currently runs
+    #[serde(default)]
+    pub deployed: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<ActionRuntime>,
+
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+
+    #[serde(default)]
+    pub secrets: Vec<ActionSecret>,
+
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ActionBuildStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub built_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Deploys the current code of an Action.
+///
+/// The Action must have finished building. Once deployed, the version runs for every
+/// trigger the Action is bound to.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:actions` scope
+/// * `action_id` - The ID of the Action to deploy
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The Action ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn deploy_action(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+) -> Result<ActionVersion> {
+    // Validate Action ID
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}/deploy"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to deploy the Action
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => response
+            .json::<ActionVersion>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_deploy_action_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/actions/actions/act_1/deploy")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "ver_3", "action_id": "act_1", "number": 3, "deployed": true, "status": "built"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = deploy_action(&domain, &token, "act_1").await;
+        mock.assert_async().await;
+
+        let version = result.unwrap();
+        assert!(version.deployed);
+        assert_eq!(version.number, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_deploy_action_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = deploy_action(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,670 @@
+//! Actions event and command models
+//!
+//! This module provides the `event` object Auth0 passes to Actions and the commands an
+//! Action issues through the `api` object. Events forwarded by an Action to a webhook
+//! deserialize into [`ActionEvent`], which reuses the crate's [`Identity`] and
+//! [`OrganizationResponse`] types. The fields present on an event depend on the trigger;
+//! `post-login` events carry all of them.
+//!
+//! Sample events for [`test_action`](super::test_action) are typed per trigger through
+//! [`TriggerEvent`]: [`PostLoginEvent`] and [`CredentialsExchangeEvent`]. The result of a
+//! test lists the issued [`ActionCommand`]s.
+//!
+//! # Example
+//!
+//! ```
+//! use mtjp9_rs_auth0_client::actions::{ActionCommand, ActionEvent};
+//!
+//! let event: ActionEvent = serde_json::from_str(r#"{
+//!     "user": {"user_id": "auth0|123", "email": "jane@example.com"},
+//!     "authorization": {"roles": ["admin"]}
+//! }"#).unwrap();
+//!
+//! assert_eq!(event.user.unwrap().user_id, "auth0|123");
+//! assert!(event.authorization.unwrap().roles.contains(&"admin".to_string()));
+//!
+//! let command = ActionCommand::SetAccessTokenClaim {
+//!     key: "https://example.com/roles".to_string(),
+//!     value: serde_json::json!(["admin"]),
+//! };
+//! assert_eq!(command.name(), "accessToken.setCustomClaim");
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 Actions documentation](https://auth0.com/docs/customize/actions/explore-triggers/signup-and-login-triggers/login-trigger/post-login-event-object)
+//! for more details about the event object and the API object.
+
+use crate::actions::TriggerId;
+use crate::organizations::OrganizationResponse;
+use crate::users::Identity;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// The `event` object passed to an Action
+///
+/// `Debug` output redacts the values of `secrets`.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct ActionEvent {
+    /// The user the trigger runs for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<EventUser>,
+
+    /// The organization the user is logging in to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<OrganizationResponse>,
+
+    /// The application the user is logging in to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<EventClient>,
+
+    /// The connection used to authenticate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<EventConnection>,
+
+    /// The request that started the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<EventRequest>,
+
+    /// The user's roles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<EventAuthorization>,
+
+    /// The authentication methods the user completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<EventAuthentication>,
+
+    /// The authorization transaction in progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<EventTransaction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<EventTenant>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<EventStats>,
+
+    /// The Action's secrets, by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub secrets: HashMap<String, String>,
+}
+
+impl fmt::Debug for ActionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionEvent")
+            .field("user", &self.user)
+            .field("organization", &self.organization)
+            .field("client", &self.client)
+            .field("connection", &self.connection)
+            .field("request", &self.request)
+            .field("authorization", &self.authorization)
+            .field("authentication", &self.authentication)
+            .field("transaction", &self.transaction)
+            .field("tenant", &self.tenant)
+            .field("stats", &self.stats)
+            .field("secrets", &RedactedSecrets(&self.secrets))
+            .finish()
+    }
+}
+
+/// An event of a specific trigger, accepted as the sample event of
+/// [`test_action`](super::test_action)
+///
+/// Implement it for the event type of a trigger not covered by this crate.
+pub trait TriggerEvent: Serialize {
+    /// The trigger whose Actions receive this event
+    const TRIGGER: TriggerId;
+}
+
+/// The event of the `post-login` trigger
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PostLoginEvent(pub ActionEvent);
+
+impl TriggerEvent for PostLoginEvent {
+    const TRIGGER: TriggerId = TriggerId::PostLogin;
+}
+
+impl From<ActionEvent> for PostLoginEvent {
+    fn from(event: ActionEvent) -> Self {
+        Self(event)
+    }
+}
+
+impl Deref for PostLoginEvent {
+    type Target = ActionEvent;
+
+    fn deref(&self) -> &ActionEvent {
+        &self.0
+    }
+}
+
+impl DerefMut for PostLoginEvent {
+    fn deref_mut(&mut self) -> &mut ActionEvent {
+        &mut self.0
+    }
+}
+
+/// The event of the `credentials-exchange` trigger, run for client credentials grants
+///
+/// `Debug` output redacts the values of `secrets`.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct CredentialsExchangeEvent {
+    /// The application requesting the access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<EventClient>,
+
+    /// The token request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<EventRequest>,
+
+    /// The API the access token is issued for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_server: Option<EventResourceServer>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<EventTenant>,
+
+    /// The scopes requested by the application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<EventTransaction>,
+
+    /// The Action's secrets, by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub secrets: HashMap<String, String>,
+}
+
+impl TriggerEvent for CredentialsExchangeEvent {
+    const TRIGGER: TriggerId = TriggerId::CredentialsExchange;
+}
+
+impl fmt::Debug for CredentialsExchangeEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CredentialsExchangeEvent")
+            .field("client", &self.client)
+            .field("request", &self.request)
+            .field("resource_server", &self.resource_server)
+            .field("tenant", &self.tenant)
+            .field("transaction", &self.transaction)
+            .field("secrets", &RedactedSecrets(&self.secrets))
+            .finish()
+    }
+}
+
+/// The `event.resource_server` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventResourceServer {
+    /// The identifier (audience) of the API
+    pub id: String,
+}
+
+/// Formats secrets by name with their values redacted
+struct RedactedSecrets<'a>(&'a HashMap<String, String>);
+
+impl fmt::Debug for RedactedSecrets<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.keys().map(|name| (name, "[REDACTED]")))
+            .finish()
+    }
+}
+
+/// The `event.user` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventUser {
+    /// The user's unique identifier
+    pub user_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_verified: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_metadata: Option<Value>,
+
+    /// The identity providers linked to the user
+    #[serde(default)]
+    pub identities: Vec<Identity>,
+
+    /// The MFA providers the user is enrolled in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multifactor: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_password_reset: Option<String>,
+}
+
+/// The `event.client` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventClient {
+    pub client_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+/// The `event.connection` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventConnection {
+    pub id: String,
+
+    pub name: String,
+
+    pub strategy: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+/// The `event.request` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventRequest {
+    /// The IP address the request came from
+    pub ip: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+
+    /// The HTTP method of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// The query string parameters of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<Value>,
+
+    /// The body of a POST request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+
+    /// Location of the IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geoip: Option<EventGeoIp>,
+}
+
+/// The `event.request.geoip` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventGeoIp {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continent_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subdivision_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+}
+
+/// The `event.authorization` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventAuthorization {
+    /// The names of the roles assigned to the user
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// The `event.authentication` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventAuthentication {
+    #[serde(default)]
+    pub methods: Vec<AuthenticationMethod>,
+}
+
+/// An authentication method the user completed, such as `pwd` or `mfa`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthenticationMethod {
+    pub name: String,
+
+    /// When the method was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+/// The `event.transaction` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventTransaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The protocol of the transaction, such as `oidc-basic-profile`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<String>,
+
+    /// The scopes requested by the application
+    #[serde(default)]
+    pub requested_scopes: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_type: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_mode: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub acr_values: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ui_locales: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_hint: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// The `event.tenant` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventTenant {
+    pub id: String,
+}
+
+/// The `event.stats` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventStats {
+    /// How many times the user has logged in
+    pub logins_count: u64,
+}
+
+/// A command issued by an Action through the `api` object
+///
+/// Commands are serialized with their method name in the `name` field, as in the result
+/// of [`test_action`](super::test_action).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawActionCommand", into = "RawActionCommand")]
+pub enum ActionCommand {
+    /// `api.accessToken.setCustomClaim(key, value)`
+    SetAccessTokenClaim { key: String, value: Value },
+
+    /// `api.idToken.setCustomClaim(key, value)`
+    SetIdTokenClaim { key: String, value: Value },
+
+    /// `api.access.deny(reason)`
+    DenyAccess { reason: String },
+
+    /// `api.redirect.sendUserTo(url, { query })`
+    Redirect { url: String, query: Option<Value> },
+
+    /// `api.user.setAppMetadata(key, value)`
+    SetAppMetadata { key: String, value: Value },
+
+    /// `api.user.setUserMetadata(key, value)`
+    SetUserMetadata { key: String, value: Value },
+
+    /// Any other command, with its arguments
+    Other {
+        name: String,
+        arguments: Map<String, Value>,
+    },
+}
+
+impl ActionCommand {
+    /// The `api` method name of the command, such as `access.deny`.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::SetAccessTokenClaim { .. } => "accessToken.setCustomClaim",
+            Self::SetIdTokenClaim { .. } => "idToken.setCustomClaim",
+            Self::DenyAccess { .. } => "access.deny",
+            Self::Redirect { .. } => "redirect.sendUserTo",
+            Self::SetAppMetadata { .. } => "user.setAppMetadata",
+            Self::SetUserMetadata { .. } => "user.setUserMetadata",
+            Self::Other { name, .. } => name,
+        }
+    }
+}
+
+/// Wire representation of a command: its name next to its arguments
+#[derive(Serialize, Deserialize)]
+struct RawActionCommand {
+    name: String,
+    #[serde(flatten)]
+    arguments: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct KeyValue {
+    key: String,
+    #[serde(default)]
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct Reason {
+    reason: String,
+}
+
+#[derive(Deserialize)]
+struct RedirectTarget {
+    url: String,
+    query: Option<Value>,
+}
+
+impl TryFrom<RawActionCommand> for ActionCommand {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawActionCommand) -> std::result::Result<Self, Self::Error> {
+        let arguments = Value::Object(raw.arguments);
+        let key_value = |arguments| serde_json::from_value::<KeyValue>(arguments);
+
+        Ok(match raw.name.as_str() {
+            "accessToken.setCustomClaim" => {
+                let KeyValue { key, value } = key_value(arguments)?;
+                Self::SetAccessTokenClaim { key, value }
+            }
+            "idToken.setCustomClaim" => {
+                let KeyValue { key, value } = key_value(arguments)?;
+                Self::SetIdTokenClaim { key, value }
+            }
+            "access.deny" => {
+                let Reason { reason } = serde_json::from_value(arguments)?;
+                Self::DenyAccess { reason }
+            }
+            "redirect.sendUserTo" => {
+                let RedirectTarget { url, query } = serde_json::from_value(arguments)?;
+                Self::Redirect { url, query }
+            }
+            "user.setAppMetadata" => {
+                let KeyValue { key, value } = key_value(arguments)?;
+                Self::SetAppMetadata { key, value }
+            }
+            "user.setUserMetadata" => {
+                let KeyValue { key, value } = key_value(arguments)?;
+                Self::SetUserMetadata { key, value }
+            }
+            _ => Self::Other {
+                name: raw.name,
+                arguments: match arguments {
+                    Value::Object(arguments) => arguments,
+                    _ => Map::new(),
+                },
+            },
+        })
+    }
+}
+
+impl From<ActionCommand> for RawActionCommand {
+    fn from(command: ActionCommand) -> Self {
+        let name = command.name().to_string();
+        let mut arguments = Map::new();
+        match command {
+            ActionCommand::SetAccessTokenClaim { key, value }
+            | ActionCommand::SetIdTokenClaim { key, value }
+            | ActionCommand::SetAppMetadata { key, value }
+            | ActionCommand::SetUserMetadata { key, value } => {
+                arguments.insert("key".to_string(), Value::String(key));
+                arguments.insert("value".to_string(), value);
+            }
+            ActionCommand::DenyAccess { reason } => {
+                arguments.insert("reason".to_string(), Value::String(reason));
+            }
+            ActionCommand::Redirect { url, query } => {
+                arguments.insert("url".to_string(), Value::String(url));
+                if let Some(query) = query {
+                    arguments.insert("query".to_string(), query);
+                }
+            }
+            ActionCommand::Other {
+                arguments: other, ..
+            } => arguments = other,
+        }
+        Self { name, arguments }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_post_login_event() {
+        let event: ActionEvent = serde_json::from_value(json!({
+            "user": {
+                "user_id": "auth0|123",
+                "email": "jane@example.com",
+                "email_verified": true,
+                "app_metadata": {"plan": "pro"},
+                "identities": [
+                    {"connection": "Username-Password-Authentication", "provider": "auth0", "user_id": "123", "isSocial": false}
+                ]
+            },
+            "organization": {"id": "org_1", "name": "acme", "display_name": "Acme", "metadata": {}},
+            "client": {"client_id": "abc", "name": "Dashboard"},
+            "request": {"ip": "203.0.113.7", "method": "GET", "geoip": {"countryCode": "NZ", "cityName": "Wellington"}},
+            "authorization": {"roles": ["admin"]},
+            "transaction": {"protocol": "oidc-basic-profile", "requested_scopes": ["openid", "profile"]},
+            "stats": {"logins_count": 4}
+        }))
+        .unwrap();
+
+        let user = event.user.unwrap();
+        assert_eq!(user.identities[0].provider, "auth0");
+        assert_eq!(user.identities[0].is_social, Some(false));
+        assert_eq!(event.organization.unwrap().name, "acme");
+        let geoip = event.request.unwrap().geoip.unwrap();
+        assert_eq!(geoip.country_code.as_deref(), Some("NZ"));
+        assert_eq!(event.transaction.unwrap().requested_scopes.len(), 2);
+        assert_eq!(event.stats.unwrap().logins_count, 4);
+    }
+
+    #[test]
+    fn test_command_round_trip() {
+        let commands: Vec<ActionCommand> = serde_json::from_value(json!([
+            {"name": "accessToken.setCustomClaim", "key": "https://example.com/roles", "value": ["admin"]},
+            {"name": "access.deny", "reason": "blocked country"},
+            {"name": "redirect.sendUserTo", "url": "https://example.com/consent"},
+            {"name": "multifactor.enable", "provider": "any"}
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            commands[0],
+            ActionCommand::SetAccessTokenClaim {
+                key: "https://example.com/roles".to_string(),
+                value: json!(["admin"]),
+            }
+        );
+        assert!(
+            matches!(&commands[1], ActionCommand::DenyAccess { reason } if reason == "blocked country")
+        );
+        assert!(matches!(
+            &commands[2],
+            ActionCommand::Redirect { query: None, .. }
+        ));
+        assert_eq!(commands[3].name(), "multifactor.enable");
+
+        let serialized = serde_json::to_value(&commands).unwrap();
+        assert_eq!(
+            serialized[1],
+            json!({"name": "access.deny", "reason": "blocked country"})
+        );
+        assert_eq!(
+            serialized[3],
+            json!({"name": "multifactor.enable", "provider": "any"})
+        );
+    }
+
+    #[test]
+    fn test_event_debug_redacts_secrets() {
+        let event = ActionEvent {
+            secrets: HashMap::from([("API_KEY".to_string(), "sk_live_123".to_string())]),
+            ..Default::default()
+        };
+
+        let output = format!("{event:?}");
+        assert!(output.contains("API_KEY"));
+        assert!(!output.contains("sk_live_123"));
+    }
+}
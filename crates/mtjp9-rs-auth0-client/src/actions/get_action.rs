@@ -0,0 +1,113 @@
+//! Action retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_action` function for fetching a single Action. It wraps
+//! the GET /api/v2/actions/actions/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/get-action)
+//! for more details about the get action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_action::Action;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches an Action by its ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `action_id` - The ID of the Action
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The Action ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_action(domain: &Domain, token: &BearerToken, action_id: &str) -> Result<Action> {
+    // Validate Action ID
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the Action
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<Action>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_action_with_deployed_version() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/actions/act_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "act_1",
+                    "name": "add-roles",
+                    "supported_triggers": [{"id": "post-login", "version": "v3"}],
+                    "status": "built",
+                    "all_changes_deployed": true,
+                    "deployed_version": {"id": "ver_2", "number": 2, "deployed": true}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_action(&domain, &token, "act_1").await;
+        mock.assert_async().await;
+
+        let action = result.unwrap();
+        assert!(action.all_changes_deployed);
+        assert_eq!(action.deployed_version.unwrap().id, "ver_2");
+    }
+
+    #[tokio::test]
+    async fn test_get_action_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_action(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,203 @@
+//! Action execution retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_execution` function for fetching the results of the
+//! Actions that ran for a trigger. It wraps the GET /api/v2/actions/executions/{id}
+//! endpoint. Execution IDs are found in the `details.actions.executions` of log events.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/get-execution)
+//! for more details about the get execution endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_action::TriggerId;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// State of an execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionStatus {
+    Pending,
+
+    /// All Actions ran
+    Final,
+
+    /// Some Actions did not run
+    Partial,
+
+    Canceled,
+
+    /// Waiting for the user to return from a redirect
+    Suspended,
+
+    #[serde(other)]
+    Other,
+}
+
+/// Error raised by an Action during an execution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Result of one Action within an execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionResult {
+    /// The name of the Action
+    pub action_name: String,
+
+    /// The error raised by the Action, if it failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ExecutionError>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<String>,
+}
+
+/// The Actions that ran for one occurrence of a trigger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Execution {
+    /// The execution's unique identifier
+    pub id: String,
+
+    pub trigger_id: TriggerId,
+
+    pub status: ExecutionStatus,
+
+    /// Results of the Actions, in execution order
+    #[serde(default)]
+    pub results: Vec<ExecutionResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl Execution {
+    /// Returns the results of the Actions that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &ExecutionResult> {
+        self.results.iter().filter(|result| result.error.is_some())
+    }
+}
+
+/// Fetches the results of an execution.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `execution_id` - The ID of the execution
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The execution ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_execution(
+    domain: &Domain,
+    token: &BearerToken,
+    execution_id: &str,
+) -> Result<Execution> {
+    // Validate execution ID
+    if execution_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Execution ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and execution ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/executions/{execution_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the execution
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<Execution>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_execution_with_failure() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/executions/exe_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "exe_1",
+                    "trigger_id": "post-login",
+                    "status": "partial",
+                    "results": [
+                        {"action_name": "add-roles", "started_at": "2025-08-06T00:00:00.000Z", "ended_at": "2025-08-06T00:00:00.120Z"},
+                        {"action_name": "audit", "error": {"id": "invalid_argument", "msg": "audit endpoint unreachable"}}
+                    ],
+                    "created_at": "2025-08-06T00:00:00.000Z"
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_execution(&domain, &token, "exe_1").await;
+        mock.assert_async().await;
+
+        let execution = result.unwrap();
+        assert_eq!(execution.status, ExecutionStatus::Partial);
+        assert_eq!(execution.trigger_id, TriggerId::PostLogin);
+        let failures: Vec<_> = execution.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].action_name, "audit");
+    }
+
+    #[tokio::test]
+    async fn test_get_execution_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_execution(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
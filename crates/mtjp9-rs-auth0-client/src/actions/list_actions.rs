@@ -0,0 +1,159 @@
+//! Action listing for Auth0 Management API v2
+//!
+//! This module provides the `list_actions` function for listing the Actions of a tenant,
+//! optionally filtered by trigger or name. It wraps the GET /api/v2/actions/actions
+//! endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/get-actions)
+//! for more details about the list actions endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_action::{Action, TriggerId};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing Actions
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListActionsQuery {
+    /// Only return Actions supporting this trigger
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<TriggerId>,
+
+    /// Only return the Action with this name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_name: Option<String>,
+
+    /// Only return deployed (true) or undeployed (false) Actions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployed: Option<bool>,
+
+    /// Only return Actions installed from the marketplace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed: Option<bool>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// A page of Actions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListActionsResponse {
+    #[serde(default)]
+    pub actions: Vec<Action>,
+
+    /// Total number of Actions matching the query
+    #[serde(default)]
+    pub total: u32,
+
+    #[serde(default)]
+    pub page: u32,
+
+    #[serde(default)]
+    pub per_page: u32,
+}
+
+/// Lists the Actions of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `query` - Optional filters and pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_actions(
+    domain: &Domain,
+    token: &BearerToken,
+    query: ListActionsQuery,
+) -> Result<ListActionsResponse> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/actions/actions");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the Actions
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ListActionsResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_actions_by_trigger() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let query = ListActionsQuery {
+            trigger_id: Some(TriggerId::PostLogin),
+            deployed: Some(true),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/actions")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("trigger_id".into(), "post-login".into()),
+                Matcher::UrlEncoded("deployed".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "actions": [{"id": "act_1", "name": "add-roles", "supported_triggers": [{"id": "post-login", "version": "v3"}]}],
+                    "total": 1,
+                    "page": 0,
+                    "per_page": 50
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_actions(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let page = result.unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.actions[0].name, "add-roles");
+    }
+}
@@ -0,0 +1,262 @@
+//! Action testing for Auth0 Management API v2
+//!
+//! This module provides the `test_action` function for running the current code of an
+//! Action against a sample event, without deploying it. It wraps the
+//! POST /api/v2/actions/actions/{id}/test endpoint. The event is typed by trigger through
+//! [`TriggerEvent`], and is only sent if the Action supports that trigger.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     actions::{test_action, ActionCommand, ActionEvent, EventUser, PostLoginEvent},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let event = PostLoginEvent(ActionEvent {
+//!         user: Some(EventUser {
+//!             user_id: "auth0|123".to_string(),
+//!             email: Some("jane@example.com".to_string()),
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     });
+//!
+//!     let result = test_action(&domain, &token, "act_1", &event).await?;
+//!     assert!(!result
+//!         .commands()?
+//!         .iter()
+//!         .any(|command| matches!(command, ActionCommand::DenyAccess { .. })));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/post-test-action)
+//! for more details about the test action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+use super::events::{ActionCommand, TriggerEvent};
+use super::get_action::get_action;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Serialize)]
+struct TestActionRequest<'a, P> {
+    payload: &'a P,
+}
+
+/// Result of an Action test run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestActionResponse {
+    /// What the Action produced, such as the commands it issued and its logs
+    #[serde(default)]
+    pub payload: Value,
+}
+
+impl TestActionResponse {
+    /// Returns the commands the Action issued, from the `commands` of the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Auth0Error::Decode` if the commands are not a list of commands.
+    pub fn commands(&self) -> Result<Vec<ActionCommand>> {
+        match self.payload.get("commands") {
+            Some(commands) => serde_json::from_value(commands.clone())
+                .map_err(|e| Auth0Error::Decode(format!("invalid Action commands: {e}"))),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Runs the current code of an Action against a sample event.
+///
+/// The Action is fetched first, to check that it supports the trigger of the event.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API requests
+/// * `token` - A valid Management API access token with the `read:actions` and
+///   `create:actions` scopes
+/// * `action_id` - The ID of the Action to test
+/// * `event` - The event passed to the Action, such as a [`PostLoginEvent`](super::PostLoginEvent)
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The Action ID is empty
+/// * The Action does not support the trigger of the event
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn test_action<E: TriggerEvent>(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    event: &E,
+) -> Result<TestActionResponse> {
+    // Validate Action ID
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Check that the Action runs for the trigger of the event
+    let action = get_action(domain, token, action_id).await?;
+    if !action
+        .supported_triggers
+        .iter()
+        .any(|trigger| trigger.id == E::TRIGGER)
+    {
+        return Err(Auth0Error::InvalidRequest(format!(
+            "Action {action_id} does not support the {} trigger",
+            E::TRIGGER
+        )));
+    }
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}/test"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to run the Action
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&TestActionRequest { payload: event })
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => response
+            .json::<TestActionResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{
+        ActionEvent, CredentialsExchangeEvent, EventResourceServer, EventUser, PostLoginEvent,
+    };
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Mock, Server, ServerGuard};
+    use serde_json::json;
+
+    async fn mock_action(server: &mut ServerGuard, trigger: &str) -> Mock {
+        server
+            .mock("GET", "/api/v2/actions/actions/act_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(format!(
+                r#"{{"id": "act_1", "name": "add-roles", "supported_triggers": [{{"id": "{trigger}", "version": "v3"}}]}}"#
+            ))
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_test_action_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let event = PostLoginEvent(ActionEvent {
+            user: Some(EventUser {
+                user_id: "auth0|123".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let action = mock_action(&mut server, "post-login").await;
+        let mock = server
+            .mock("POST", "/api/v2/actions/actions/act_1/test")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"payload": {"user": {"user_id": "auth0|123", "identities": []}}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"payload": {"logs": "", "commands": [{"name": "accessToken.setCustomClaim", "key": "https://example.com/roles", "value": ["admin"]}]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = test_action(&domain, &token, "act_1", &event).await;
+        action.assert_async().await;
+        mock.assert_async().await;
+
+        let commands = result.unwrap().commands().unwrap();
+        assert_eq!(
+            commands,
+            vec![ActionCommand::SetAccessTokenClaim {
+                key: "https://example.com/roles".to_string(),
+                value: json!(["admin"]),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_test_action_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = test_action(&domain, &token, "", &PostLoginEvent::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_test_action_rejects_event_of_other_trigger() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _action = mock_action(&mut server, "post-login").await;
+        let test = server
+            .mock("POST", "/api/v2/actions/actions/act_1/test")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let event = CredentialsExchangeEvent {
+            resource_server: Some(EventResourceServer {
+                id: "https://api.example.com".to_string(),
+            }),
+            ..Default::default()
+        };
+        let result = test_action(&domain, &token, "act_1", &event).await;
+        test.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,412 @@
+//! Action trigger management for Auth0 Management API v2
+//!
+//! This module provides functions for listing the available triggers and for binding
+//! deployed Actions to a trigger. It wraps the /api/v2/actions/triggers endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     actions::{TriggerBindingUpdate, TriggerId, update_trigger_bindings},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Run "add-roles" after login, followed by "audit"
+//!     let bindings = vec![
+//!         TriggerBindingUpdate::action_name("add-roles"),
+//!         TriggerBindingUpdate::action_name("audit"),
+//!     ];
+//!     update_trigger_bindings(&domain, &token, &TriggerId::PostLogin, bindings).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/patch-bindings)
+//! for more details about the trigger bindings endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_action::{Action, ActionRuntime, ActionSecret, ActionTrigger, TriggerId};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A trigger Actions can be bound to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trigger {
+    pub id: TriggerId,
+
+    /// Version of the trigger
+    pub version: String,
+
+    /// Status of the trigger version (e.g. "CURRENT", "DEPRECATED")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Runtimes available for the trigger
+    #[serde(default)]
+    pub runtimes: Vec<ActionRuntime>,
+
+    /// Runtime used when an Action does not set one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_runtime: Option<ActionRuntime>,
+
+    /// Other triggers Actions written for this trigger can also be bound to
+    #[serde(default)]
+    pub compatible_triggers: Vec<ActionTrigger>,
+}
+
+#[derive(Deserialize)]
+struct TriggersResponse {
+    #[serde(default)]
+    triggers: Vec<Trigger>,
+}
+
+/// An Action bound to a trigger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerBinding {
+    /// The binding's unique identifier
+    pub id: String,
+
+    pub trigger_id: TriggerId,
+
+    /// Name of the binding shown in the flow editor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// The bound Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Query parameters for listing trigger bindings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListTriggerBindingsQuery {
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// A page of trigger bindings, in execution order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTriggerBindingsResponse {
+    #[serde(default)]
+    pub bindings: Vec<TriggerBinding>,
+
+    #[serde(default)]
+    pub total: u32,
+
+    #[serde(default)]
+    pub page: u32,
+
+    #[serde(default)]
+    pub per_page: u32,
+}
+
+/// Reference to the Action of a binding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ActionRef {
+    ActionId(String),
+    ActionName(String),
+}
+
+/// A binding in a trigger bindings update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerBindingUpdate {
+    /// The Action to bind
+    #[serde(rename = "ref")]
+    pub action_ref: ActionRef,
+
+    /// Name of the binding shown in the flow editor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Secrets of the binding, for Actions installed from the marketplace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionSecret>>,
+}
+
+impl TriggerBindingUpdate {
+    /// Binds the Action with the given ID.
+    pub fn action_id(id: impl Into<String>) -> Self {
+        Self::new(ActionRef::ActionId(id.into()))
+    }
+
+    /// Binds the Action with the given name.
+    pub fn action_name(name: impl Into<String>) -> Self {
+        Self::new(ActionRef::ActionName(name.into()))
+    }
+
+    fn new(action_ref: ActionRef) -> Self {
+        Self {
+            action_ref,
+            display_name: None,
+            secrets: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct UpdateBindingsBody<B> {
+    bindings: Vec<B>,
+}
+
+/// Lists the triggers Actions can be bound to.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn list_triggers(domain: &Domain, token: &BearerToken) -> Result<Vec<Trigger>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/actions/triggers");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the triggers
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => Ok(response.json::<TriggersResponse>().await?.triggers),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Lists the Actions bound to a trigger.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `trigger_id` - The trigger
+/// * `query` - Pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_trigger_bindings(
+    domain: &Domain,
+    token: &BearerToken,
+    trigger_id: &TriggerId,
+    query: ListTriggerBindingsQuery,
+) -> Result<ListTriggerBindingsResponse> {
+    // Construct the API endpoint URL using the provided domain and trigger ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/triggers/{trigger_id}/bindings"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the bindings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ListTriggerBindingsResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Replaces the Actions bound to a trigger.
+///
+/// The bindings run in the given order. Actions missing from the list are unbound, so an
+/// empty list removes all bindings of the trigger.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:triggers` scope
+/// * `trigger_id` - The trigger
+/// * `bindings` - The deployed Actions to bind, in execution order
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn update_trigger_bindings(
+    domain: &Domain,
+    token: &BearerToken,
+    trigger_id: &TriggerId,
+    bindings: Vec<TriggerBindingUpdate>,
+) -> Result<Vec<TriggerBinding>> {
+    // Construct the API endpoint URL using the provided domain and trigger ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/triggers/{trigger_id}/bindings"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to replace the bindings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&UpdateBindingsBody { bindings })
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => Ok(response
+            .json::<UpdateBindingsBody<TriggerBinding>>()
+            .await?
+            .bindings),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_triggers() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/triggers")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"triggers": [
+                    {"id": "post-login", "version": "v3", "status": "CURRENT", "runtimes": ["node18", "node22"], "default_runtime": "node22"},
+                    {"id": "pre-user-registration", "version": "v2", "status": "CURRENT"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_triggers(&domain, &token).await;
+        mock.assert_async().await;
+
+        let triggers = result.unwrap();
+        assert_eq!(triggers[0].default_runtime, Some(ActionRuntime::Node22));
+        assert_eq!(triggers[1].id, TriggerId::PreUserRegistration);
+    }
+
+    #[tokio::test]
+    async fn test_get_trigger_bindings() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/triggers/post-login/bindings")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "bindings": [{"id": "bnd_1", "trigger_id": "post-login", "display_name": "add-roles", "action": {"id": "act_1", "name": "add-roles"}}],
+                    "total": 1,
+                    "page": 0,
+                    "per_page": 20
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_trigger_bindings(
+            &domain,
+            &token,
+            &TriggerId::PostLogin,
+            ListTriggerBindingsQuery::default(),
+        )
+        .await;
+        mock.assert_async().await;
+
+        let bindings = result.unwrap();
+        assert_eq!(bindings.bindings[0].action.as_ref().unwrap().id, "act_1");
+    }
+
+    #[tokio::test]
+    async fn test_update_trigger_bindings() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let bindings = vec![
+            TriggerBindingUpdate::action_id("act_1"),
+            TriggerBindingUpdate {
+                display_name: Some("Audit".to_string()),
+                ..TriggerBindingUpdate::action_name("audit")
+            },
+        ];
+
+        let mock = server
+            .mock("PATCH", "/api/v2/actions/triggers/post-login/bindings")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"bindings": [
+                    {"ref": {"type": "action_id", "value": "act_1"}},
+                    {"ref": {"type": "action_name", "value": "audit"}, "display_name": "Audit"}
+                ]}"#
+                .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"bindings": [
+                    {"id": "bnd_1", "trigger_id": "post-login", "display_name": "add-roles"},
+                    {"id": "bnd_2", "trigger_id": "post-login", "display_name": "Audit"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result =
+            update_trigger_bindings(&domain, &token, &TriggerId::PostLogin, bindings).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,155 @@
+//! Action updates for Auth0 Management API v2
+//!
+//! This module provides the `update_action` function for changing the code or
+//! configuration of an Action. It wraps the PATCH /api/v2/actions/actions/{id} endpoint.
+//! Changes only take effect once the Action is deployed again.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/patch-action)
+//! for more details about the update action endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_action::{Action, ActionDependency, ActionRuntime, ActionSecret, ActionTrigger};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating an Action
+///
+/// All fields are optional. Only fields that are provided will be updated. `secrets`
+/// replaces all secrets of the Action; a secret given without a value keeps its current
+/// value.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateActionRequest {
+    /// The name of the Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The triggers the Action can be bound to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_triggers: Option<Vec<ActionTrigger>>,
+
+    /// The source code of the Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// The Node.js runtime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<ActionRuntime>,
+
+    /// npm packages the Action depends on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<ActionDependency>>,
+
+    /// Secrets available to the Action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionSecret>>,
+}
+
+/// Updates an Action.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:actions` scope
+/// * `action_id` - The ID of the Action to update
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The Action ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_action(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    request: UpdateActionRequest,
+) -> Result<Action> {
+    // Validate Action ID
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the Action
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<Action>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_action_code() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UpdateActionRequest {
+            code: Some("exports.onExecutePostLogin = async () => { /* v2 */ };".to_string()),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/actions/actions/act_1")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"code": "exports.onExecutePostLogin = async () => { /* v2 */ };"}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "act_1", "name": "add-roles", "status": "building", "all_changes_deployed": false}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_action(&domain, &token, "act_1", request).await;
+        mock.assert_async().await;
+
+        assert!(!result.unwrap().all_changes_deployed);
+    }
+
+    #[tokio::test]
+    async fn test_update_action_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = update_action(&domain, &token, "", UpdateActionRequest::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,333 @@
+//! Action version management for Auth0 Management API v2
+//!
+//! This module provides functions for listing and inspecting the deployed versions of an
+//! Action, and for rolling an Action back to an earlier version. It wraps the
+//! /api/v2/actions/actions/{id}/versions endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     actions::{ListActionVersionsQuery, list_action_versions, rollback_action},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Roll back to the version deployed before the current one
+//!     let versions = list_action_versions(&domain, &token, "act_1", ListActionVersionsQuery::default()).await?;
+//!     if let Some(previous) = versions.versions.iter().find(|version| !version.deployed) {
+//!         rollback_action(&domain, &token, "act_1", &previous.id, true).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/actions/get-action-versions)
+//! for more details about the action versions endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+
+use super::deploy_action::ActionVersion;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing Action versions
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListActionVersionsQuery {
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// A page of Action versions, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListActionVersionsResponse {
+    #[serde(default)]
+    pub versions: Vec<ActionVersion>,
+
+    /// Total number of versions of the Action
+    #[serde(default)]
+    pub total: u32,
+
+    #[serde(default)]
+    pub page: u32,
+
+    #[serde(default)]
+    pub per_page: u32,
+}
+
+fn validate_ids(action_id: &str, version_id: Option<&str>) -> Result<()> {
+    if action_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Action ID cannot be empty".to_string(),
+        ));
+    }
+    if version_id.is_some_and(str::is_empty) {
+        return Err(Auth0Error::InvalidRequest(
+            "Version ID cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Lists the versions of an Action.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `action_id` - The ID of the Action
+/// * `query` - Pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the Action ID is empty, the request fails or the API
+/// returns an error response.
+pub async fn list_action_versions(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    query: ListActionVersionsQuery,
+) -> Result<ListActionVersionsResponse> {
+    // Validate Action ID
+    validate_ids(action_id, None)?;
+
+    // Construct the API endpoint URL using the provided domain and Action ID
+    let endpoint = domain.to_url(&format!("/api/v2/actions/actions/{action_id}/versions"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the versions
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ListActionVersionsResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Fetches a version of an Action.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:actions` scope
+/// * `action_id` - The ID of the Action
+/// * `version_id` - The ID of the version
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if either ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn get_action_version(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    version_id: &str,
+) -> Result<ActionVersion> {
+    // Validate Action and version IDs
+    validate_ids(action_id, Some(version_id))?;
+
+    // Construct the API endpoint URL using the provided domain and IDs
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/actions/actions/{action_id}/versions/{version_id}"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the version
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ActionVersion>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Deploys an earlier version of an Action again.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:actions` scope
+/// * `action_id` - The ID of the Action
+/// * `version_id` - The ID of the version to deploy
+/// * `update_draft` - Whether the Action's current code is replaced by the version's code
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if either ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn rollback_action(
+    domain: &Domain,
+    token: &BearerToken,
+    action_id: &str,
+    version_id: &str,
+    update_draft: bool,
+) -> Result<ActionVersion> {
+    // Validate Action and version IDs
+    validate_ids(action_id, Some(version_id))?;
+
+    // Construct the API endpoint URL using the provided domain and IDs
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/actions/actions/{action_id}/versions/{version_id}/deploy"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to deploy the version
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .json(&json!({ "update_draft": update_draft }))
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => response
+            .json::<ActionVersion>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_action_versions() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/actions/act_1/versions")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "versions": [
+                        {"id": "ver_2", "number": 2, "deployed": true},
+                        {"id": "ver_1", "number": 1, "deployed": false}
+                    ],
+                    "total": 2,
+                    "page": 0,
+                    "per_page": 20
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result =
+            list_action_versions(&domain, &token, "act_1", ListActionVersionsQuery::default())
+                .await;
+        mock.assert_async().await;
+
+        let versions = result.unwrap();
+        assert_eq!(versions.total, 2);
+        assert!(!versions.versions[1].deployed);
+    }
+
+    #[tokio::test]
+    async fn test_get_action_version() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/actions/actions/act_1/versions/ver_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "ver_1", "number": 1, "code": "exports.onExecutePostLogin = async () => {};"}"#)
+            .create_async()
+            .await;
+
+        let result = get_action_version(&domain, &token, "act_1", "ver_1").await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().code.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_action() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "POST",
+                "/api/v2/actions/actions/act_1/versions/ver_1/deploy",
+            )
+            .match_body(Matcher::JsonString(r#"{"update_draft": true}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "ver_3", "number": 3, "deployed": true}"#)
+            .create_async()
+            .await;
+
+        let result = rollback_action(&domain, &token, "act_1", "ver_1", true).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().number, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_action_empty_version_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = rollback_action(&domain, &token, "act_1", "", false).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,23 @@
+//! Attack protection functionality for Auth0
+//!
+//! This module provides functions for configuring brute-force protection, breached
+//! password detection and suspicious IP throttling through the Auth0 Management API v2.
+
+mod breached_password_detection;
+mod brute_force_protection;
+mod shield;
+mod suspicious_ip_throttling;
+
+pub use breached_password_detection::{
+    get_breached_password_detection, update_breached_password_detection,
+    AdminNotificationFrequency, BreachedPasswordDetection, BreachedPasswordMethod,
+    BreachedPasswordStage, BreachedPasswordStages,
+};
+pub use brute_force_protection::{
+    get_brute_force_protection, update_brute_force_protection, BruteForceMode, BruteForceProtection,
+};
+pub use shield::Shield;
+pub use suspicious_ip_throttling::{
+    get_suspicious_ip_throttling, update_suspicious_ip_throttling, SuspiciousIpThrottling,
+    ThrottlingStages, ThrottlingThreshold,
+};
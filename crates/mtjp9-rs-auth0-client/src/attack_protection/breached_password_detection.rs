@@ -0,0 +1,244 @@
+//! Breached password detection configuration for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and updating breached password detection,
+//! which acts when a user logs in, signs up or changes their password with credentials
+//! found in a third-party data breach. It wraps the
+//! /api/v2/attack-protection/breached-password-detection endpoints.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/attack-protection/patch-breached-password-detection)
+//! for more details about the breached password detection endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::shield::Shield;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often administrators are notified of breached passwords
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminNotificationFrequency {
+    Immediately,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Source of breach data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachedPasswordMethod {
+    Standard,
+    /// Faster detection with more breach sources; requires the Credential Guard add-on
+    Enhanced,
+}
+
+/// Shields applied at one stage of breached password detection
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachedPasswordStage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+}
+
+/// Stage-specific settings, overriding the shields applied at login
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachedPasswordStages {
+    /// When a user signs up
+    #[serde(
+        rename = "pre-user-registration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_user_registration: Option<BreachedPasswordStage>,
+
+    /// When a user changes their password
+    #[serde(
+        rename = "pre-change-password",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_change_password: Option<BreachedPasswordStage>,
+}
+
+/// Breached password detection settings
+///
+/// When updating, only fields that are provided are changed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachedPasswordDetection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// What happens when a user logs in with a breached password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+
+    /// When the `admin_notification` shield emails administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_notification_frequency: Option<Vec<AdminNotificationFrequency>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<BreachedPasswordMethod>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<BreachedPasswordStages>,
+}
+
+/// Fetches the breached password detection settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:attack_protection` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_breached_password_detection(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<BreachedPasswordDetection> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/breached-password-detection");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BreachedPasswordDetection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Updates the breached password detection settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:attack_protection` scope
+/// * `settings` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn update_breached_password_detection(
+    domain: &Domain,
+    token: &BearerToken,
+    settings: &BreachedPasswordDetection,
+) -> Result<BreachedPasswordDetection> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/breached-password-detection");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(settings)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BreachedPasswordDetection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_breached_password_detection_stages() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let body = r#"{
+            "enabled": true,
+            "shields": ["block", "admin_notification"],
+            "admin_notification_frequency": ["daily"],
+            "stage": {"pre-user-registration": {"shields": ["block"]}}
+        }"#;
+        let mock = server
+            .mock(
+                "PATCH",
+                "/api/v2/attack-protection/breached-password-detection",
+            )
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(body.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let settings = BreachedPasswordDetection {
+            enabled: Some(true),
+            shields: Some(vec![Shield::Block, Shield::AdminNotification]),
+            admin_notification_frequency: Some(vec![AdminNotificationFrequency::Daily]),
+            stage: Some(BreachedPasswordStages {
+                pre_user_registration: Some(BreachedPasswordStage {
+                    shields: Some(vec![Shield::Block]),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = update_breached_password_detection(&domain, &token, &settings).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), settings);
+    }
+
+    #[tokio::test]
+    async fn test_get_breached_password_detection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/attack-protection/breached-password-detection")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"enabled": false, "shields": [], "admin_notification_frequency": [], "method": "standard", "stage": {"pre-user-registration": {"shields": []}, "pre-change-password": {"shields": []}}}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_breached_password_detection(&domain, &token).await;
+        mock.assert_async().await;
+
+        let settings = result.unwrap();
+        assert_eq!(settings.method, Some(BreachedPasswordMethod::Standard));
+        assert!(settings.stage.unwrap().pre_change_password.is_some());
+    }
+}
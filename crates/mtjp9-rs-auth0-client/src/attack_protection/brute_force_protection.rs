@@ -0,0 +1,228 @@
+//! Brute-force protection configuration for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and updating brute-force protection, which
+//! blocks logins to an account after repeated failed attempts. It wraps the
+//! /api/v2/attack-protection/brute-force-protection endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     attack_protection::{BruteForceProtection, Shield, update_brute_force_protection},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let settings = BruteForceProtection {
+//!         enabled: Some(true),
+//!         shields: Some(vec![Shield::Block, Shield::UserNotification]),
+//!         allowlist: Some(vec!["203.0.113.0/24".to_string()]),
+//!         max_attempts: Some(5),
+//!         ..Default::default()
+//!     };
+//!
+//!     update_brute_force_protection(&domain, &token, &settings).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/attack-protection/patch-brute-force-protection)
+//! for more details about the brute-force protection endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::shield::Shield;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How failed login attempts are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BruteForceMode {
+    /// Per account and IP address
+    CountPerIdentifierAndIp,
+    /// Per account, from any IP address
+    CountPerIdentifier,
+}
+
+/// Brute-force protection settings
+///
+/// When updating, only fields that are provided are changed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BruteForceProtection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// What happens when the threshold is reached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+
+    /// IP addresses and CIDR ranges that are never blocked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<BruteForceMode>,
+
+    /// Failed attempts allowed before the shields apply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+}
+
+/// Fetches the brute-force protection settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:attack_protection` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_brute_force_protection(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<BruteForceProtection> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/brute-force-protection");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BruteForceProtection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Updates the brute-force protection settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:attack_protection` scope
+/// * `settings` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn update_brute_force_protection(
+    domain: &Domain,
+    token: &BearerToken,
+    settings: &BruteForceProtection,
+) -> Result<BruteForceProtection> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/brute-force-protection");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(settings)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BruteForceProtection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_get_brute_force_protection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/attack-protection/brute-force-protection")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"enabled": true, "shields": ["block", "user_notification"], "allowlist": [], "mode": "count_per_identifier_and_ip", "max_attempts": 10}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_brute_force_protection(&domain, &token).await;
+        mock.assert_async().await;
+
+        let settings = result.unwrap();
+        assert_eq!(
+            settings.shields,
+            Some(vec![Shield::Block, Shield::UserNotification])
+        );
+        assert_eq!(settings.mode, Some(BruteForceMode::CountPerIdentifierAndIp));
+        assert_eq!(settings.max_attempts, Some(10));
+    }
+
+    #[tokio::test]
+    async fn test_update_brute_force_protection_threshold() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/attack-protection/brute-force-protection")
+            .match_body(Matcher::JsonString(r#"{"max_attempts": 5}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"enabled": true, "shields": ["block"], "max_attempts": 5}"#)
+            .create_async()
+            .await;
+
+        let settings = BruteForceProtection {
+            max_attempts: Some(5),
+            ..Default::default()
+        };
+        let result = update_brute_force_protection(&domain, &token, &settings).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().max_attempts, Some(5));
+    }
+}
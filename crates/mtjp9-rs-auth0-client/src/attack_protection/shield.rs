@@ -0,0 +1,58 @@
+//! Shared attack protection types
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Action taken when an attack protection feature detects an attack
+///
+/// Shields not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Shield {
+    /// `block`: reject the login, signup or password change
+    Block,
+    /// `user_notification`: email the affected user
+    UserNotification,
+    /// `admin_notification`: email the tenant administrators
+    AdminNotification,
+    /// Any other shield
+    Other(String),
+}
+
+impl Shield {
+    /// The name Auth0 uses for this shield.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Block => "block",
+            Self::UserNotification => "user_notification",
+            Self::AdminNotification => "admin_notification",
+            Self::Other(shield) => shield,
+        }
+    }
+}
+
+impl From<String> for Shield {
+    fn from(shield: String) -> Self {
+        match shield.as_str() {
+            "block" => Self::Block,
+            "user_notification" => Self::UserNotification,
+            "admin_notification" => Self::AdminNotification,
+            _ => Self::Other(shield),
+        }
+    }
+}
+
+impl From<Shield> for String {
+    fn from(shield: Shield) -> Self {
+        match shield {
+            Shield::Other(shield) => shield,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Shield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,219 @@
+//! Suspicious IP throttling configuration for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and updating suspicious IP throttling,
+//! which slows down IP addresses that attempt many logins or signups across accounts. It
+//! wraps the /api/v2/attack-protection/suspicious-ip-throttling endpoints.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/attack-protection/patch-suspicious-ip-throttling)
+//! for more details about the suspicious IP throttling endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::shield::Shield;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Throttling threshold of one stage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottlingThreshold {
+    /// Attempts an IP address may make before it is throttled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+
+    /// Milliseconds after which one more attempt is allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+
+/// Thresholds for logins and signups
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottlingStages {
+    #[serde(rename = "pre-login", skip_serializing_if = "Option::is_none")]
+    pub pre_login: Option<ThrottlingThreshold>,
+
+    #[serde(
+        rename = "pre-user-registration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_user_registration: Option<ThrottlingThreshold>,
+}
+
+/// Suspicious IP throttling settings
+///
+/// When updating, only fields that are provided are changed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// What happens when an IP address is throttled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+
+    /// IP addresses and CIDR ranges that are never throttled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ThrottlingStages>,
+}
+
+/// Fetches the suspicious IP throttling settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:attack_protection` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_suspicious_ip_throttling(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<SuspiciousIpThrottling> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/suspicious-ip-throttling");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<SuspiciousIpThrottling>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Updates the suspicious IP throttling settings.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:attack_protection` scope
+/// * `settings` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn update_suspicious_ip_throttling(
+    domain: &Domain,
+    token: &BearerToken,
+    settings: &SuspiciousIpThrottling,
+) -> Result<SuspiciousIpThrottling> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/attack-protection/suspicious-ip-throttling");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(settings)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<SuspiciousIpThrottling>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_get_suspicious_ip_throttling_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/attack-protection/suspicious-ip-throttling")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "enabled": true,
+                    "shields": ["admin_notification", "block"],
+                    "allowlist": ["198.51.100.4"],
+                    "stage": {
+                        "pre-login": {"max_attempts": 100, "rate": 864000},
+                        "pre-user-registration": {"max_attempts": 50, "rate": 1200}
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_suspicious_ip_throttling(&domain, &token).await;
+        mock.assert_async().await;
+
+        let settings = result.unwrap();
+        let stage = settings.stage.unwrap();
+        assert_eq!(stage.pre_login.unwrap().max_attempts, Some(100));
+        assert_eq!(stage.pre_user_registration.unwrap().rate, Some(1200));
+        assert_eq!(settings.allowlist, Some(vec!["198.51.100.4".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_update_suspicious_ip_throttling_allowlist() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "PATCH",
+                "/api/v2/attack-protection/suspicious-ip-throttling",
+            )
+            .match_body(Matcher::JsonString(
+                r#"{"allowlist": ["198.51.100.0/24"]}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"enabled": true, "allowlist": ["198.51.100.0/24"]}"#)
+            .create_async()
+            .await;
+
+        let settings = SuspiciousIpThrottling {
+            allowlist: Some(vec!["198.51.100.0/24".to_string()]),
+            ..Default::default()
+        };
+        let result = update_suspicious_ip_throttling(&domain, &token, &settings).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().enabled, Some(true));
+    }
+}
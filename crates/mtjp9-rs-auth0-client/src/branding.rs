@@ -0,0 +1,23 @@
+//! Branding functionality for Auth0
+//!
+//! This module provides functions for managing the tenant-level branding of the Universal
+//! Login pages through the Auth0 Management API v2: branding settings, the theme and the
+//! page template.
+
+mod get_branding;
+mod themes;
+mod universal_login;
+mod update_branding;
+
+pub use get_branding::{
+    get_branding, BackgroundGradient, BrandingFont, BrandingSettings, PageBackground,
+    TenantBrandingColors,
+};
+pub use themes::{
+    create_branding_theme, delete_branding_theme, get_branding_theme, get_default_branding_theme,
+    update_branding_theme, BrandingTheme,
+};
+pub use universal_login::{
+    delete_universal_login_template, get_universal_login_template, put_universal_login_template,
+};
+pub use update_branding::{update_branding, UpdateBrandingRequest};
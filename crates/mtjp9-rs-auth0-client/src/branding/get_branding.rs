@@ -0,0 +1,209 @@
+//! Tenant branding retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_branding` function for fetching the branding settings
+//! of the tenant, which apply to the Universal Login pages of every application. It wraps
+//! the GET /api/v2/branding endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/branding/get-branding)
+//! for more details about the get branding endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Custom font of the login pages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrandingFont {
+    /// URL of the font file
+    pub url: String,
+}
+
+/// Gradient page background of the login pages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundGradient {
+    /// Kind of gradient, such as `linear-gradient`
+    #[serde(rename = "type")]
+    pub gradient_type: String,
+
+    /// Start color (hex format)
+    pub start: String,
+
+    /// End color (hex format)
+    pub end: String,
+
+    /// Angle of the gradient in degrees
+    pub angle_deg: i32,
+}
+
+/// Page background of the login pages: a single color or a gradient
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PageBackground {
+    /// A color (hex format, e.g., "#FFFFFF")
+    Color(String),
+    Gradient(BackgroundGradient),
+}
+
+/// Color configuration of the tenant branding
+///
+/// Unlike organization [`BrandingColors`](crate::organizations::BrandingColors), the page
+/// background of the tenant can be a gradient.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TenantBrandingColors {
+    /// Primary color (hex format, e.g., "#FF5733")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_background: Option<PageBackground>,
+}
+
+/// Branding settings of the tenant
+///
+/// Organizations override these settings with their own
+/// [`OrganizationBranding`](crate::organizations::OrganizationBranding).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BrandingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<TenantBrandingColors>,
+
+    /// URL of the logo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    /// URL of the favicon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favicon_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font: Option<BrandingFont>,
+}
+
+/// Fetches the branding settings of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:branding` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_branding(domain: &Domain, token: &BearerToken) -> Result<BrandingSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the branding settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BrandingSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_branding_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/branding")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r##"{
+                    "colors": {"primary": "#0059d6", "page_background": "#000000"},
+                    "logo_url": "https://cdn.example.com/logo.png",
+                    "font": {"url": "https://cdn.example.com/inter.woff2"}
+                }"##,
+            )
+            .create_async()
+            .await;
+
+        let result = get_branding(&domain, &token).await;
+        mock.assert_async().await;
+
+        let branding = result.unwrap();
+        let colors = branding.colors.unwrap();
+        assert_eq!(colors.primary.as_deref(), Some("#0059d6"));
+        assert_eq!(
+            colors.page_background,
+            Some(PageBackground::Color("#000000".to_string()))
+        );
+        assert_eq!(branding.favicon_url, None);
+        assert_eq!(
+            branding.font.unwrap().url,
+            "https://cdn.example.com/inter.woff2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_branding_gradient_background() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/branding")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r##"{
+                    "colors": {
+                        "primary": "#0059d6",
+                        "page_background": {
+                            "type": "linear-gradient",
+                            "start": "#ffffff",
+                            "end": "#0059d6",
+                            "angle_deg": 35
+                        }
+                    }
+                }"##,
+            )
+            .create_async()
+            .await;
+
+        let branding = get_branding(&domain, &token).await.unwrap();
+
+        let expected = PageBackground::Gradient(BackgroundGradient {
+            gradient_type: "linear-gradient".to_string(),
+            start: "#ffffff".to_string(),
+            end: "#0059d6".to_string(),
+            angle_deg: 35,
+        });
+        assert_eq!(branding.colors.unwrap().page_background, Some(expected));
+    }
+}
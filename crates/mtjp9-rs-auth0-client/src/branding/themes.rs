@@ -0,0 +1,407 @@
+//! Universal Login theme management for Auth0 Management API v2
+//!
+//! This module provides functions for creating, fetching, updating and deleting the theme
+//! of the Universal Login pages. It wraps the /api/v2/branding/themes endpoints. A tenant
+//! has at most one theme.
+//!
+//! The sections of a theme hold many style settings that Auth0 extends over time, so they
+//! are kept as JSON objects. This lets a theme exported from the dashboard be stored as
+//! JSON and applied unchanged.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     branding::{BrandingTheme, create_branding_theme, get_default_branding_theme, update_branding_theme},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, fs};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Theme checked into the repository
+//!     let json = fs::read_to_string("branding/theme.json").map_err(|e| Auth0Error::InvalidRequest(e.to_string()))?;
+//!     let theme: BrandingTheme = serde_json::from_str(&json).map_err(|e| Auth0Error::InvalidRequest(e.to_string()))?;
+//!
+//!     match get_default_branding_theme(&domain, &token).await? {
+//!         Some(current) => update_branding_theme(&domain, &token, &current.theme_id, &theme).await?,
+//!         None => create_branding_theme(&domain, &token, &theme).await?,
+//!     };
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/branding/post-branding-theme)
+//! for more details about the branding themes endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A Universal Login theme
+///
+/// Creating or updating a theme requires all sections.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandingTheme {
+    /// The theme's unique identifier, set by Auth0 and never sent
+    #[serde(default, skip_serializing)]
+    pub theme_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Border radius and weight of buttons, inputs and the widget
+    #[serde(default)]
+    pub borders: Map<String, Value>,
+
+    /// Colors of text, buttons, links and states
+    #[serde(default)]
+    pub colors: Map<String, Value>,
+
+    /// Font sizes and weights, and the reference text size
+    #[serde(default)]
+    pub fonts: Map<String, Value>,
+
+    /// Page background color and layout
+    #[serde(rename = "page_background", default)]
+    pub page_background: Map<String, Value>,
+
+    /// Logo position, header alignment and social button layout
+    #[serde(default)]
+    pub widget: Map<String, Value>,
+}
+
+/// Creates the Universal Login theme.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:branding` scope
+/// * `theme` - The theme with all its sections
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response,
+/// such as 409 Conflict when the tenant already has a theme.
+pub async fn create_branding_theme(
+    domain: &Domain,
+    token: &BearerToken,
+    theme: &BrandingTheme,
+) -> Result<BrandingTheme> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding/themes");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the theme
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(theme)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => response
+            .json::<BrandingTheme>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Fetches a Universal Login theme.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:branding` scope
+/// * `theme_id` - The ID of the theme
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn get_branding_theme(
+    domain: &Domain,
+    token: &BearerToken,
+    theme_id: &str,
+) -> Result<BrandingTheme> {
+    // Validate theme ID
+    if theme_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Theme ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and theme ID
+    let endpoint = domain.to_url(&format!("/api/v2/branding/themes/{theme_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the theme
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BrandingTheme>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Fetches the theme in use, if the tenant has one.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:branding` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response
+/// other than 404.
+pub async fn get_default_branding_theme(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<Option<BrandingTheme>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding/themes/default");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the default theme
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BrandingTheme>()
+            .await
+            .map(Some)
+            .map_err(Auth0Error::from),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Replaces the sections of a Universal Login theme.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:branding` scope
+/// * `theme_id` - The ID of the theme
+/// * `theme` - The theme with all its sections
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn update_branding_theme(
+    domain: &Domain,
+    token: &BearerToken,
+    theme_id: &str,
+    theme: &BrandingTheme,
+) -> Result<BrandingTheme> {
+    // Validate theme ID
+    if theme_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Theme ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and theme ID
+    let endpoint = domain.to_url(&format!("/api/v2/branding/themes/{theme_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the theme
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(theme)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BrandingTheme>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Deletes a Universal Login theme, restoring the default look.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:branding` scope
+/// * `theme_id` - The ID of the theme
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn delete_branding_theme(
+    domain: &Domain,
+    token: &BearerToken,
+    theme_id: &str,
+) -> Result<()> {
+    // Validate theme ID
+    if theme_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Theme ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and theme ID
+    let endpoint = domain.to_url(&format!("/api/v2/branding/themes/{theme_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the theme
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    const THEME: &str = r##"{
+        "themeId": "thm_1",
+        "displayName": "Acme",
+        "borders": {"button_border_radius": 3, "widget_corner_radius": 5},
+        "colors": {"primary_button": "#0059d6", "widget_background": "#ffffff"},
+        "fonts": {"font_url": "", "reference_text_size": 16},
+        "page_background": {"background_color": "#000000", "page_layout": "center"},
+        "widget": {"logo_position": "center", "social_buttons_layout": "bottom"}
+    }"##;
+
+    #[tokio::test]
+    async fn test_update_branding_theme_round_trip() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let default = server
+            .mock("GET", "/api/v2/branding/themes/default")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(THEME)
+            .create_async()
+            .await;
+
+        let theme = get_default_branding_theme(&domain, &token)
+            .await
+            .unwrap()
+            .unwrap();
+        default.assert_async().await;
+        assert_eq!(theme.theme_id, "thm_1");
+        assert_eq!(theme.widget["logo_position"], json!("center"));
+
+        // The fetched theme is sent back without its ID
+        let update = server
+            .mock("PATCH", "/api/v2/branding/themes/thm_1")
+            .match_body(Matcher::Json(json!({
+                "displayName": "Acme",
+                "borders": {"button_border_radius": 3, "widget_corner_radius": 5},
+                "colors": {"primary_button": "#0059d6", "widget_background": "#ffffff"},
+                "fonts": {"font_url": "", "reference_text_size": 16},
+                "page_background": {"background_color": "#000000", "page_layout": "center"},
+                "widget": {"logo_position": "center", "social_buttons_layout": "bottom"}
+            })))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(THEME)
+            .create_async()
+            .await;
+
+        let result = update_branding_theme(&domain, &token, &theme.theme_id, &theme).await;
+        update.assert_async().await;
+
+        assert_eq!(result.unwrap(), theme);
+    }
+
+    #[tokio::test]
+    async fn test_get_default_branding_theme_none() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/branding/themes/default")
+            .with_status(404)
+            .with_body(r#"{"message": "There is no default theme"}"#)
+            .create_async()
+            .await;
+
+        let result = get_default_branding_theme(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_delete_branding_theme_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = delete_branding_theme(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
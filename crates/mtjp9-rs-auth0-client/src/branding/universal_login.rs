@@ -0,0 +1,219 @@
+//! Universal Login page template management for Auth0 Management API v2
+//!
+//! This module provides functions for fetching, setting and deleting the Liquid template
+//! the Universal Login pages are rendered in. It wraps the
+//! /api/v2/branding/templates/universal-login endpoints. Templates require a custom domain.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/branding/put-universal-login)
+//! for more details about the universal login template endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The Universal Login template as returned by the Management API
+#[derive(Deserialize)]
+struct UniversalLoginTemplate {
+    body: String,
+}
+
+/// Fetches the Universal Login page template.
+///
+/// Returns `None` if the tenant uses the default page.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:branding` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response
+/// other than 404.
+pub async fn get_universal_login_template(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<Option<String>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding/templates/universal-login");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the template
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<UniversalLoginTemplate>()
+            .await
+            .map(|template| Some(template.body))
+            .map_err(Auth0Error::from),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Sets the Universal Login page template.
+///
+/// The template must contain the `{%- auth0:head -%}` and `{%- auth0:widget -%}` tags.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:branding` scope
+/// * `template` - The Liquid template of the page
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the template is empty, the request fails or the API returns
+/// an error response, such as 402 when the tenant has no custom domain.
+pub async fn put_universal_login_template(
+    domain: &Domain,
+    token: &BearerToken,
+    template: &str,
+) -> Result<()> {
+    // Validate template
+    if template.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Template cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding/templates/universal-login");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the template
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&json!({ "template": template }))
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Deletes the Universal Login page template, restoring the default page.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:branding` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn delete_universal_login_template(domain: &Domain, token: &BearerToken) -> Result<()> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding/templates/universal-login");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the template
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    const TEMPLATE: &str =
+        "<!DOCTYPE html><html><head>{%- auth0:head -%}</head><body>{%- auth0:widget -%}</body></html>";
+
+    #[tokio::test]
+    async fn test_put_and_get_universal_login_template() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let put = server
+            .mock("PUT", "/api/v2/branding/templates/universal-login")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::Json(json!({ "template": TEMPLATE })))
+            .with_status(204)
+            .create_async()
+            .await;
+        let get = server
+            .mock("GET", "/api/v2/branding/templates/universal-login")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(json!({ "body": TEMPLATE }).to_string())
+            .create_async()
+            .await;
+
+        put_universal_login_template(&domain, &token, TEMPLATE)
+            .await
+            .unwrap();
+        let template = get_universal_login_template(&domain, &token).await;
+        put.assert_async().await;
+        get.assert_async().await;
+
+        assert_eq!(template.unwrap().as_deref(), Some(TEMPLATE));
+    }
+
+    #[tokio::test]
+    async fn test_get_universal_login_template_default_page() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/branding/templates/universal-login")
+            .with_status(404)
+            .with_body(r#"{"message": "Template does not exist"}"#)
+            .create_async()
+            .await;
+
+        let result = get_universal_login_template(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_put_universal_login_template_empty() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = put_universal_login_template(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
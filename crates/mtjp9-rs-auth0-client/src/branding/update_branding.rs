@@ -0,0 +1,155 @@
+//! Tenant branding updates for Auth0 Management API v2
+//!
+//! This module provides the `update_branding` function for changing the branding settings
+//! of the tenant. It wraps the PATCH /api/v2/branding endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     branding::{PageBackground, TenantBrandingColors, UpdateBrandingRequest, update_branding},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = UpdateBrandingRequest {
+//!         colors: Some(TenantBrandingColors {
+//!             primary: Some("#0059d6".to_string()),
+//!             page_background: Some(PageBackground::Color("#ffffff".to_string())),
+//!         }),
+//!         logo_url: Some("https://cdn.example.com/logo.png".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     update_branding(&domain, &token, request).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/branding/patch-branding)
+//! for more details about the update branding endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::get_branding::{BrandingFont, BrandingSettings, TenantBrandingColors};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating the branding settings
+///
+/// All fields are optional. Only fields that are provided will be updated.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdateBrandingRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<TenantBrandingColors>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favicon_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font: Option<BrandingFont>,
+}
+
+/// Updates the branding settings of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:branding` scope
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_branding(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UpdateBrandingRequest,
+) -> Result<BrandingSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/branding");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the branding settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<BrandingSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_branding_favicon() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/branding")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"favicon_url": "https://cdn.example.com/favicon.ico"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"favicon_url": "https://cdn.example.com/favicon.ico"}"#)
+            .create_async()
+            .await;
+
+        let request = UpdateBrandingRequest {
+            favicon_url: Some("https://cdn.example.com/favicon.ico".to_string()),
+            ..Default::default()
+        };
+        let result = update_branding(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(
+            result.unwrap().favicon_url.as_deref(),
+            Some("https://cdn.example.com/favicon.ico")
+        );
+    }
+}
@@ -0,0 +1,14 @@
+//! Client grant management functionality for Auth0
+//!
+//! This module provides functions for granting applications access to resource servers
+//! through the Auth0 Management API v2.
+
+mod create_client_grant;
+mod delete_client_grant;
+mod list_client_grants;
+mod update_client_grant;
+
+pub use create_client_grant::{create_client_grant, ClientGrant, CreateClientGrantRequest};
+pub use delete_client_grant::delete_client_grant;
+pub use list_client_grants::{list_client_grants, ListClientGrantsQuery};
+pub use update_client_grant::{update_client_grant, UpdateClientGrantRequest};
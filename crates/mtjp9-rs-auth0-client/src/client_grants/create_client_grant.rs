@@ -0,0 +1,258 @@
+//! Client grant creation functionality for Auth0 Management API v2
+//!
+//! This module provides the `create_client_grant` function for granting an application
+//! scopes on a resource server. It wraps the POST /api/v2/client-grants endpoint.
+//!
+//! Once a grant exists, the client can obtain a token for the resource server with
+//! `oauth::get_oauth_token` using the `client_credentials` grant and a matching
+//! `audience`/`scope`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     client_grants::{CreateClientGrantRequest, create_client_grant},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateClientGrantRequest {
+//!         client_id: "client_abc".to_string(),
+//!         audience: "https://api.example.com".to_string(),
+//!         scope: vec!["read:things".to_string()],
+//!         ..Default::default()
+//!     };
+//!
+//!     let grant = create_client_grant(&domain, &token, request).await?;
+//!     println!("Created client grant {}", grant.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/client-grants/post-client-grants)
+//! for more details about the client grant creation endpoint.
+
+use crate::{
+    clients::OrganizationUsage,
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for creating a client grant
+///
+/// See: <https://auth0.com/docs/api/management/v2/client-grants/post-client-grants>
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateClientGrantRequest {
+    /// The ID of the client to grant access to
+    pub client_id: String,
+
+    /// The audience (resource server identifier) the grant applies to
+    pub audience: String,
+
+    /// The scopes to grant
+    pub scope: Vec<String>,
+
+    /// How the grant may be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// Whether the grant applies to any organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_any_organization: Option<bool>,
+}
+
+/// A client grant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientGrant {
+    /// The client grant's unique identifier
+    pub id: String,
+
+    /// The ID of the client the grant belongs to
+    pub client_id: String,
+
+    /// The audience (resource server identifier) of the grant
+    pub audience: String,
+
+    /// The granted scopes
+    #[serde(default)]
+    pub scope: Vec<String>,
+
+    /// How the grant may be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// Whether the grant applies to any organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_any_organization: Option<bool>,
+}
+
+/// Creates a client grant in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:client_grants` scope
+/// * `request` - The client, audience and scopes to grant
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The client ID or audience is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes), including a conflict
+///   if a grant for the client and audience already exists
+/// * The response cannot be deserialized
+pub async fn create_client_grant(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateClientGrantRequest,
+) -> Result<ClientGrant> {
+    // Validate client_id and audience
+    if request.client_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client ID cannot be empty".to_string(),
+        ));
+    }
+    if request.audience.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Audience cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/client-grants");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the client grant
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<ClientGrant>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_create_client_grant_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateClientGrantRequest {
+            client_id: "client_abc".to_string(),
+            audience: "https://api.example.com".to_string(),
+            scope: vec!["read:things".to_string(), "write:things".to_string()],
+            organization_usage: Some(OrganizationUsage::Allow),
+            allow_any_organization: Some(false),
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/client-grants")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                serde_json::to_string(&request).unwrap(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "cgr_1",
+                    "client_id": "client_abc",
+                    "audience": "https://api.example.com",
+                    "scope": ["read:things", "write:things"],
+                    "organization_usage": "allow",
+                    "allow_any_organization": false
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = create_client_grant(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let grant = result.unwrap();
+        assert_eq!(grant.id, "cgr_1");
+        assert_eq!(grant.scope.len(), 2);
+        assert_eq!(grant.organization_usage, Some(OrganizationUsage::Allow));
+    }
+
+    #[tokio::test]
+    async fn test_create_client_grant_missing_audience() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateClientGrantRequest {
+            client_id: "client_abc".to_string(),
+            ..Default::default()
+        };
+
+        match create_client_grant(&domain, &token, request)
+            .await
+            .unwrap_err()
+        {
+            Auth0Error::InvalidRequest(msg) => assert_eq!(msg, "Audience cannot be empty"),
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_client_grant_conflict() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/client-grants")
+            .with_status(409)
+            .with_body(r#"{"statusCode": 409, "message": "A resource with the same identifier already exists"}"#)
+            .create_async()
+            .await;
+
+        let request = CreateClientGrantRequest {
+            client_id: "client_abc".to_string(),
+            audience: "https://api.example.com".to_string(),
+            ..Default::default()
+        };
+
+        let result = create_client_grant(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Conflict { .. })));
+    }
+}
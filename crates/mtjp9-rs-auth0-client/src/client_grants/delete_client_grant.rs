@@ -0,0 +1,114 @@
+//! Client grant deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_client_grant` function for revoking a client grant.
+//! It wraps the DELETE /api/v2/client-grants/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/client-grants/delete-client-grants-by-id)
+//! for more details about the delete client grant endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes a client grant, revoking the client's access to the audience.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:client_grants` scope
+/// * `grant_id` - The ID of the client grant to delete
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The grant ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_client_grant(
+    domain: &Domain,
+    token: &BearerToken,
+    grant_id: &str,
+) -> Result<()> {
+    // Validate grant_id
+    if grant_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Grant ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and grant ID
+    let endpoint = domain.to_url(&format!("/api/v2/client-grants/{grant_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the client grant
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_delete_client_grant_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/client-grants/cgr_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = delete_client_grant(&domain, &token, "cgr_1").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_client_grant_not_found() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/client-grants/cgr_missing")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "Client grant not found"}"#)
+            .create_async()
+            .await;
+
+        let result = delete_client_grant(&domain, &token, "cgr_missing").await;
+        mock.assert_async().await;
+
+        assert!(matches!(
+            result,
+            Err(Auth0Error::UnexpectedResponse { status: 404, .. })
+        ));
+    }
+}
@@ -0,0 +1,144 @@
+//! Client grant listing for Auth0 Management API v2
+//!
+//! This module provides the `list_client_grants` function for listing client grants,
+//! optionally filtered by audience and client. It wraps the GET /api/v2/client-grants endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/client-grants/get-client-grants)
+//! for more details about the list client grants endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_client_grant::ClientGrant;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing client grants
+///
+/// All fields are optional. Only fields that are provided are sent as filters.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListClientGrantsQuery {
+    /// Only return grants for this audience
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Only return grants for this client ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Only return grants that apply to any organization (`true`) or not (`false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_any_organization: Option<bool>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Lists client grants in the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:client_grants` scope
+/// * `query` - Optional filters and pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_client_grants(
+    domain: &Domain,
+    token: &BearerToken,
+    query: ListClientGrantsQuery,
+) -> Result<Vec<ClientGrant>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/client-grants");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the client grants
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<ClientGrant>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_client_grants_by_audience_and_client() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/client-grants")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("audience".into(), "https://api.example.com".into()),
+                Matcher::UrlEncoded("client_id".into(), "client_abc".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{
+                    "id": "cgr_1",
+                    "client_id": "client_abc",
+                    "audience": "https://api.example.com",
+                    "scope": ["read:things"]
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let query = ListClientGrantsQuery {
+            audience: Some("https://api.example.com".to_string()),
+            client_id: Some("client_abc".to_string()),
+            ..Default::default()
+        };
+
+        let result = list_client_grants(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let grants = result.unwrap();
+        assert_eq!(grants.len(), 1);
+        assert_eq!(grants[0].scope, vec!["read:things"]);
+    }
+
+    #[test]
+    fn test_list_client_grants_query_empty_serialization() {
+        let json = serde_json::to_value(ListClientGrantsQuery::default()).unwrap();
+        assert_eq!(json, serde_json::json!({}));
+    }
+}
@@ -0,0 +1,149 @@
+//! Client grant update functionality for Auth0 Management API v2
+//!
+//! This module provides the `update_client_grant` function for changing the scopes or
+//! organization settings of a client grant. It wraps the PATCH /api/v2/client-grants/{id}
+//! endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/client-grants/patch-client-grants-by-id)
+//! for more details about the client grant update endpoint.
+
+use crate::{
+    clients::OrganizationUsage,
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_client_grant::ClientGrant;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating a client grant
+///
+/// All fields are optional. Only fields that are provided will be updated.
+/// `scope` replaces the existing list of scopes entirely.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateClientGrantRequest {
+    /// The scopes to grant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Vec<String>>,
+
+    /// How the grant may be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// Whether the grant applies to any organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_any_organization: Option<bool>,
+}
+
+/// Updates a client grant in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:client_grants` scope
+/// * `grant_id` - The ID of the client grant to update
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The grant ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_client_grant(
+    domain: &Domain,
+    token: &BearerToken,
+    grant_id: &str,
+    request: UpdateClientGrantRequest,
+) -> Result<ClientGrant> {
+    // Validate grant_id
+    if grant_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Grant ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and grant ID
+    let endpoint = domain.to_url(&format!("/api/v2/client-grants/{grant_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the client grant
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ClientGrant>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_update_client_grant_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UpdateClientGrantRequest {
+            scope: Some(vec!["read:things".to_string()]),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/client-grants/cgr_1")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"scope": ["read:things"]}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "cgr_1",
+                    "client_id": "client_abc",
+                    "audience": "https://api.example.com",
+                    "scope": ["read:things"]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_client_grant(&domain, &token, "cgr_1", request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().scope, vec!["read:things"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_client_grant_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = update_client_grant(&domain, &token, "", Default::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,27 @@
+//! Client (application) management functionality for Auth0
+//!
+//! This module provides functions for managing applications and their credentials through
+//! the Auth0 Management API v2.
+
+mod create_client;
+mod credentials;
+mod delete_client;
+mod get_client;
+mod list_clients;
+mod rotate_secret;
+mod update_client;
+
+pub use create_client::{
+    create_client, AppType, ClientAuthenticationMethods, ClientResponse, CreateClientRequest,
+    CredentialReference, JwtConfiguration, OrganizationRequireBehavior, OrganizationUsage,
+    PrivateKeyJwt, TokenEndpointAuthMethod,
+};
+pub use credentials::{
+    create_credential, delete_credential, list_credentials, ClientCredential,
+    CreateClientCredentialRequest,
+};
+pub use delete_client::delete_client;
+pub use get_client::get_client;
+pub use list_clients::{list_clients, ListClientsQuery};
+pub use rotate_secret::rotate_secret;
+pub use update_client::{update_client, UpdateClientRequest};
@@ -0,0 +1,645 @@
+//! Client (application) creation functionality for Auth0 Management API v2
+//!
+//! This module provides the `create_client` function for creating new applications
+//! in Auth0. It wraps the POST /api/v2/clients endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     clients::{AppType, CreateClientRequest, create_client},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateClientRequest {
+//!         name: "My SPA".to_string(),
+//!         app_type: Some(AppType::Spa),
+//!         callbacks: Some(vec!["https://app.example.com/callback".to_string()]),
+//!         web_origins: Some(vec!["https://app.example.com".to_string()]),
+//!         ..Default::default()
+//!     };
+//!
+//!     let client = create_client(&domain, &token, request).await?;
+//!     println!("Created client: {} (ID: {})", client.name, client.client_id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/post-clients)
+//! for more details about the client creation endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The type of application
+///
+/// Types not modelled here are kept in `Other`, so they survive a read-modify-write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum AppType {
+    /// Native / mobile application
+    Native,
+
+    /// Single page application
+    Spa,
+
+    /// Regular (server-side) web application
+    RegularWeb,
+
+    /// Machine-to-machine application
+    NonInteractive,
+
+    /// Any other application type (e.g. SSO integrations)
+    Other(String),
+}
+
+impl AppType {
+    /// The name Auth0 uses for this application type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Native => "native",
+            Self::Spa => "spa",
+            Self::RegularWeb => "regular_web",
+            Self::NonInteractive => "non_interactive",
+            Self::Other(app_type) => app_type,
+        }
+    }
+}
+
+impl From<String> for AppType {
+    fn from(app_type: String) -> Self {
+        match app_type.as_str() {
+            "native" => Self::Native,
+            "spa" => Self::Spa,
+            "regular_web" => Self::RegularWeb,
+            "non_interactive" => Self::NonInteractive,
+            _ => Self::Other(app_type),
+        }
+    }
+}
+
+impl From<AppType> for String {
+    fn from(app_type: AppType) -> Self {
+        match app_type {
+            AppType::Other(app_type) => app_type,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AppType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How the client authenticates at the token endpoint
+///
+/// Methods not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TokenEndpointAuthMethod {
+    /// Public client, no secret
+    None,
+
+    /// Client secret sent in the request body
+    ClientSecretPost,
+
+    /// Client secret sent using HTTP Basic authentication
+    ClientSecretBasic,
+
+    /// Any other authentication method
+    Other(String),
+}
+
+impl TokenEndpointAuthMethod {
+    /// The name Auth0 uses for this authentication method.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::ClientSecretPost => "client_secret_post",
+            Self::ClientSecretBasic => "client_secret_basic",
+            Self::Other(method) => method,
+        }
+    }
+}
+
+impl From<String> for TokenEndpointAuthMethod {
+    fn from(method: String) -> Self {
+        match method.as_str() {
+            "none" => Self::None,
+            "client_secret_post" => Self::ClientSecretPost,
+            "client_secret_basic" => Self::ClientSecretBasic,
+            _ => Self::Other(method),
+        }
+    }
+}
+
+impl From<TokenEndpointAuthMethod> for String {
+    fn from(method: TokenEndpointAuthMethod) -> Self {
+        match method {
+            TokenEndpointAuthMethod::Other(method) => method,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TokenEndpointAuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether the client can be used with organizations
+///
+/// Values not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OrganizationUsage {
+    /// Organizations are not supported
+    Deny,
+
+    /// Organizations are optional
+    Allow,
+
+    /// An organization is required
+    Require,
+
+    /// Any other value
+    Other(String),
+}
+
+impl OrganizationUsage {
+    /// The name Auth0 uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Deny => "deny",
+            Self::Allow => "allow",
+            Self::Require => "require",
+            Self::Other(usage) => usage,
+        }
+    }
+}
+
+impl From<String> for OrganizationUsage {
+    fn from(usage: String) -> Self {
+        match usage.as_str() {
+            "deny" => Self::Deny,
+            "allow" => Self::Allow,
+            "require" => Self::Require,
+            _ => Self::Other(usage),
+        }
+    }
+}
+
+impl From<OrganizationUsage> for String {
+    fn from(usage: OrganizationUsage) -> Self {
+        match usage {
+            OrganizationUsage::Other(usage) => usage,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How the login flow behaves when an organization is required
+///
+/// Behaviors not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OrganizationRequireBehavior {
+    /// No prompt; the organization must be passed in the authorization request
+    NoPrompt,
+
+    /// Prompt the user for the organization before login
+    PreLoginPrompt,
+
+    /// Prompt the user to pick one of their organizations after login
+    PostLoginPrompt,
+
+    /// Any other behavior
+    Other(String),
+}
+
+impl OrganizationRequireBehavior {
+    /// The name Auth0 uses for this behavior.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NoPrompt => "no_prompt",
+            Self::PreLoginPrompt => "pre_login_prompt",
+            Self::PostLoginPrompt => "post_login_prompt",
+            Self::Other(behavior) => behavior,
+        }
+    }
+}
+
+impl From<String> for OrganizationRequireBehavior {
+    fn from(behavior: String) -> Self {
+        match behavior.as_str() {
+            "no_prompt" => Self::NoPrompt,
+            "pre_login_prompt" => Self::PreLoginPrompt,
+            "post_login_prompt" => Self::PostLoginPrompt,
+            _ => Self::Other(behavior),
+        }
+    }
+}
+
+impl From<OrganizationRequireBehavior> for String {
+    fn from(behavior: OrganizationRequireBehavior) -> Self {
+        match behavior {
+            OrganizationRequireBehavior::Other(behavior) => behavior,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationRequireBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// JWT settings for tokens issued to the client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct JwtConfiguration {
+    /// Number of seconds the issued ID token is valid for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifetime_in_seconds: Option<u32>,
+
+    /// Whether the client secret is base64 encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_encoded: Option<bool>,
+
+    /// Scopes to include in the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<serde_json::Value>,
+
+    /// Algorithm used to sign JWTs (`HS256` or `RS256`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+}
+
+/// Reference to a client credential by ID
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredentialReference {
+    /// The credential ID
+    pub id: String,
+}
+
+/// `private_key_jwt` authentication configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrivateKeyJwt {
+    /// Credentials the client may use to sign client assertions
+    pub credentials: Vec<CredentialReference>,
+}
+
+/// Alternative client authentication methods
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ClientAuthenticationMethods {
+    /// `private_key_jwt` authentication configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_jwt: Option<PrivateKeyJwt>,
+}
+
+/// Request body for creating a new client
+///
+/// See: <https://auth0.com/docs/api/management/v2/clients/post-clients>
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateClientRequest {
+    /// The name of the client
+    pub name: String,
+
+    /// Free text description of the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The type of application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<AppType>,
+
+    /// Whether this is a first party client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_party: Option<bool>,
+
+    /// Allowed callback URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callbacks: Option<Vec<String>>,
+
+    /// Allowed URLs to redirect to after logout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+
+    /// Allowed origins for cross-origin authentication and web message response mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_origins: Option<Vec<String>>,
+
+    /// Allowed origins for CORS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+
+    /// Grant types the client may use (e.g. "authorization_code", "client_credentials")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_types: Option<Vec<String>>,
+
+    /// How the client authenticates at the token endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<TokenEndpointAuthMethod>,
+
+    /// Alternative client authentication methods such as `private_key_jwt`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_authentication_methods: Option<ClientAuthenticationMethods>,
+
+    /// JWT settings for tokens issued to the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_configuration: Option<JwtConfiguration>,
+
+    /// Whether the client can be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// How the login flow behaves when an organization is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_require_behavior: Option<OrganizationRequireBehavior>,
+
+    /// Metadata associated with the client (string values only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_metadata: Option<serde_json::Value>,
+}
+
+/// Response from creating or fetching a client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientResponse {
+    /// The client's unique identifier
+    pub client_id: String,
+
+    /// The name of the client
+    pub name: String,
+
+    /// The client secret (only returned for confidential clients)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Free text description of the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The type of application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<AppType>,
+
+    /// Whether this is a first party client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_party: Option<bool>,
+
+    /// Allowed callback URLs
+    #[serde(default)]
+    pub callbacks: Vec<String>,
+
+    /// Allowed URLs to redirect to after logout
+    #[serde(default)]
+    pub allowed_logout_urls: Vec<String>,
+
+    /// Allowed origins for cross-origin authentication
+    #[serde(default)]
+    pub web_origins: Vec<String>,
+
+    /// Allowed origins for CORS
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+
+    /// Grant types the client may use
+    #[serde(default)]
+    pub grant_types: Vec<String>,
+
+    /// How the client authenticates at the token endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<TokenEndpointAuthMethod>,
+
+    /// Alternative client authentication methods such as `private_key_jwt`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_authentication_methods: Option<ClientAuthenticationMethods>,
+
+    /// JWT settings for tokens issued to the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_configuration: Option<JwtConfiguration>,
+
+    /// Whether the client can be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// How the login flow behaves when an organization is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_require_behavior: Option<OrganizationRequireBehavior>,
+
+    /// Metadata associated with the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_metadata: Option<serde_json::Value>,
+}
+
+/// Creates a new client (application) in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:clients` scope
+/// * `request` - The client configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The client name is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+///
+/// # Rate Limiting
+///
+/// Auth0 enforces rate limits on Management API endpoints. If you receive a 429 error,
+/// implement exponential backoff before retrying.
+pub async fn create_client(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateClientRequest,
+) -> Result<ClientResponse> {
+    // Validate name
+    if request.name.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client name cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/clients");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the client
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<ClientResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_create_client_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateClientRequest {
+            name: "Backend M2M".to_string(),
+            app_type: Some(AppType::NonInteractive),
+            grant_types: Some(vec!["client_credentials".to_string()]),
+            token_endpoint_auth_method: Some(TokenEndpointAuthMethod::ClientSecretPost),
+            ..Default::default()
+        };
+
+        let response_body = r#"{
+            "client_id": "client_abc",
+            "client_secret": "shh",
+            "name": "Backend M2M",
+            "app_type": "non_interactive",
+            "grant_types": ["client_credentials"],
+            "token_endpoint_auth_method": "client_secret_post",
+            "jwt_configuration": {"lifetime_in_seconds": 36000, "alg": "RS256"}
+        }"#;
+
+        let mock = server
+            .mock("POST", "/api/v2/clients")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                serde_json::to_string(&request).unwrap(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(response_body)
+            .create_async()
+            .await;
+
+        let result = create_client(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let client = result.unwrap();
+        assert_eq!(client.client_id, "client_abc");
+        assert_eq!(client.client_secret, Some("shh".to_string()));
+        assert_eq!(client.app_type, Some(AppType::NonInteractive));
+        assert_eq!(
+            client.jwt_configuration.unwrap().lifetime_in_seconds,
+            Some(36000)
+        );
+        assert!(client.callbacks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_client_empty_name() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = create_client(&domain, &token, CreateClientRequest::default()).await;
+
+        match result.unwrap_err() {
+            Auth0Error::InvalidRequest(msg) => assert_eq!(msg, "Client name cannot be empty"),
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_create_client_request_serialization() {
+        let request = CreateClientRequest {
+            name: "SPA".to_string(),
+            app_type: Some(AppType::Spa),
+            organization_usage: Some(OrganizationUsage::Require),
+            organization_require_behavior: Some(OrganizationRequireBehavior::PostLoginPrompt),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["app_type"], "spa");
+        assert_eq!(json["organization_usage"], "require");
+        assert_eq!(json["organization_require_behavior"], "post_login_prompt");
+        assert!(!json.as_object().unwrap().contains_key("callbacks"));
+    }
+
+    #[test]
+    fn test_unknown_app_type_round_trip() {
+        let app_type: AppType = serde_json::from_str(r#""sso_integration""#).unwrap();
+        assert_eq!(app_type, AppType::Other("sso_integration".to_string()));
+        assert_eq!(
+            serde_json::to_string(&app_type).unwrap(),
+            r#""sso_integration""#
+        );
+    }
+
+    #[test]
+    fn test_unknown_organization_settings() {
+        let usage: OrganizationUsage = serde_json::from_str(r#""optional""#).unwrap();
+        assert_eq!(usage, OrganizationUsage::Other("optional".to_string()));
+
+        let behavior: OrganizationRequireBehavior =
+            serde_json::from_str(r#""post_login_prompt""#).unwrap();
+        assert_eq!(behavior, OrganizationRequireBehavior::PostLoginPrompt);
+        assert_eq!(
+            serde_json::to_string(&OrganizationRequireBehavior::Other("pick".to_string())).unwrap(),
+            r#""pick""#
+        );
+    }
+
+    #[test]
+    fn test_unknown_token_endpoint_auth_method() {
+        let method: TokenEndpointAuthMethod = serde_json::from_str(r#""tls_client_auth""#).unwrap();
+        assert_eq!(
+            method,
+            TokenEndpointAuthMethod::Other("tls_client_auth".to_string())
+        );
+        assert_eq!(
+            serde_json::to_string(&TokenEndpointAuthMethod::ClientSecretBasic).unwrap(),
+            r#""client_secret_basic""#
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Client credentials management for Auth0 Management API v2
+//!
+//! This module provides functions for managing the public keys an application uses for
+//! `private_key_jwt` authentication. It wraps the /api/v2/clients/{id}/credentials endpoints.
+//!
+//! A newly created credential must also be referenced from the client's
+//! `client_authentication_methods.private_key_jwt.credentials` (see `update_client`)
+//! before it can be used.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     clients::{CreateClientCredentialRequest, create_credential},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateClientCredentialRequest {
+//!         name: Some("2025 signing key".to_string()),
+//!         pem: "-----BEGIN PUBLIC KEY-----\n...\n-----END PUBLIC KEY-----".to_string(),
+//!         alg: Some("RS256".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     let credential = create_credential(&domain, &token, "client_abc", request).await?;
+//!     println!("Created credential {} (kid: {:?})", credential.id, credential.kid);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/post-credentials)
+//! for more details about the client credentials endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn default_credential_type() -> String {
+    "public_key".to_string()
+}
+
+/// Request body for creating a client credential
+///
+/// See: <https://auth0.com/docs/api/management/v2/clients/post-credentials>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateClientCredentialRequest {
+    /// The credential type. Defaults to "public_key".
+    #[serde(default = "default_credential_type")]
+    pub credential_type: String,
+
+    /// A friendly name for the credential
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// PEM-formatted public key or X.509 certificate
+    pub pem: String,
+
+    /// Algorithm used to verify client assertions (`RS256`, `RS384` or `PS256`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    /// Whether to take the expiry from the certificate in `pem`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_expiry_from_cert: Option<bool>,
+
+    /// ISO 8601 timestamp at which the credential expires
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl Default for CreateClientCredentialRequest {
+    fn default() -> Self {
+        Self {
+            credential_type: default_credential_type(),
+            name: None,
+            pem: String::new(),
+            alg: None,
+            parse_expiry_from_cert: None,
+            expires_at: None,
+        }
+    }
+}
+
+/// A credential registered for a client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientCredential {
+    /// The credential's unique identifier
+    pub id: String,
+
+    /// The credential's friendly name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The key identifier of the credential
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+
+    /// Algorithm used to verify client assertions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    /// The credential type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_type: Option<String>,
+
+    /// When the credential was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// When the credential was last updated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+
+    /// When the credential expires
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+fn validate_client_id(client_id: &str) -> Result<()> {
+    if client_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client ID cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Lists the credentials registered for a client.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:client_credentials` scope
+/// * `client_id` - The ID of the client
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the client ID is empty, the request fails or the API
+/// returns an error response.
+pub async fn list_credentials(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+) -> Result<Vec<ClientCredential>> {
+    // Validate client ID
+    validate_client_id(client_id)?;
+
+    // Construct the API endpoint URL using the provided domain and client ID
+    let endpoint = domain.to_url(&format!("/api/v2/clients/{client_id}/credentials"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the credentials
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<ClientCredential>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Registers a new public key credential for a client.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:client_credentials` scope
+/// * `client_id` - The ID of the client
+/// * `request` - The credential to register
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the client ID or PEM is empty, the request fails or the API
+/// returns an error response.
+pub async fn create_credential(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+    request: CreateClientCredentialRequest,
+) -> Result<ClientCredential> {
+    // Validate client ID and PEM
+    validate_client_id(client_id)?;
+
+    if request.pem.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "PEM cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and client ID
+    let endpoint = domain.to_url(&format!("/api/v2/clients/{client_id}/credentials"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to register the credential
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<ClientCredential>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Deletes a credential from a client.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:client_credentials` scope
+/// * `client_id` - The ID of the client
+/// * `credential_id` - The ID of the credential to delete
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if either ID is empty, the request fails or the API returns an
+/// error response.
+pub async fn delete_credential(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+    credential_id: &str,
+) -> Result<()> {
+    // Validate client ID and credential ID
+    validate_client_id(client_id)?;
+
+    if credential_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Credential ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain, client ID and credential ID
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/clients/{client_id}/credentials/{credential_id}"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the credential
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_create_credential_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateClientCredentialRequest {
+            name: Some("key-1".to_string()),
+            pem: "-----BEGIN PUBLIC KEY-----".to_string(),
+            alg: Some("RS256".to_string()),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/clients/client_abc/credentials")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{
+                    "credential_type": "public_key",
+                    "name": "key-1",
+                    "pem": "-----BEGIN PUBLIC KEY-----",
+                    "alg": "RS256"
+                }"#
+                .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "cred_1", "name": "key-1", "kid": "kid_1", "alg": "RS256"}"#)
+            .create_async()
+            .await;
+
+        let result = create_credential(&domain, &token, "client_abc", request).await;
+        mock.assert_async().await;
+
+        let credential = result.unwrap();
+        assert_eq!(credential.id, "cred_1");
+        assert_eq!(credential.kid, Some("kid_1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_credential_empty_pem() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = create_credential(&domain, &token, "client_abc", Default::default()).await;
+
+        match result.unwrap_err() {
+            Auth0Error::InvalidRequest(msg) => assert_eq!(msg, "PEM cannot be empty"),
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_and_delete_credentials() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let list = server
+            .mock("GET", "/api/v2/clients/client_abc/credentials")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[{"id": "cred_1"}, {"id": "cred_2"}]"#)
+            .create_async()
+            .await;
+        let delete = server
+            .mock("DELETE", "/api/v2/clients/client_abc/credentials/cred_1")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let credentials = list_credentials(&domain, &token, "client_abc")
+            .await
+            .unwrap();
+        assert_eq!(credentials.len(), 2);
+
+        delete_credential(&domain, &token, "client_abc", &credentials[0].id)
+            .await
+            .unwrap();
+
+        list.assert_async().await;
+        delete.assert_async().await;
+    }
+}
@@ -0,0 +1,133 @@
+//! Client (application) retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_client` function for fetching a single application.
+//! It wraps the GET /api/v2/clients/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/get-clients-by-id)
+//! for more details about the get client endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_client::ClientResponse;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches a client (application) by its client ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:clients` scope
+///   (and `read:client_keys` to include the client secret)
+/// * `client_id` - The ID of the client to fetch
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The client ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_client(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+) -> Result<ClientResponse> {
+    // Validate client_id
+    if client_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and client ID
+    let endpoint = domain.to_url(&format!("/api/v2/clients/{client_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the client
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ClientResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_client_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/clients/client_abc")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "client_id": "client_abc",
+                    "name": "My SPA",
+                    "app_type": "spa",
+                    "callbacks": ["https://app.example.com/callback"],
+                    "organization_usage": "allow"
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_client(&domain, &token, "client_abc").await;
+        mock.assert_async().await;
+
+        let client = result.unwrap();
+        assert_eq!(client.name, "My SPA");
+        assert_eq!(client.callbacks, vec!["https://app.example.com/callback"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_client_not_found() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/clients/client_missing")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "Client not found"}"#)
+            .create_async()
+            .await;
+
+        let result = get_client(&domain, &token, "client_missing").await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            Auth0Error::UnexpectedResponse { status, .. } => assert_eq!(status, 404),
+            _ => panic!("Expected UnexpectedResponse error with 404 status"),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Client (application) listing for Auth0 Management API v2
+//!
+//! This module provides the `list_clients` function for listing applications.
+//! It wraps the GET /api/v2/clients endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/get-clients)
+//! for more details about the list clients endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_client::ClientResponse;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing clients
+///
+/// All fields are optional. Only fields that are provided are sent as filters.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListClientsQuery {
+    /// Comma-separated list of fields to include or exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+
+    /// Whether `fields` lists the fields to include (`true`) or exclude (`false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+
+    /// Only return global (`true`) or non-global (`false`) clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_global: Option<bool>,
+
+    /// Only return first party (`true`) or third party (`false`) clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_party: Option<bool>,
+
+    /// Comma-separated list of application types to return (e.g. "spa,non_interactive")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<String>,
+}
+
+/// Lists the clients (applications) in the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:clients` scope
+/// * `query` - Optional filters and pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_clients(
+    domain: &Domain,
+    token: &BearerToken,
+    query: ListClientsQuery,
+) -> Result<Vec<ClientResponse>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/clients");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the clients
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<ClientResponse>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_clients_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/clients")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("app_type".into(), "spa,non_interactive".into()),
+                Matcher::UrlEncoded("is_global".into(), "false".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"client_id": "client_spa", "name": "SPA", "app_type": "spa"},
+                    {"client_id": "client_m2m", "name": "M2M", "app_type": "non_interactive"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let query = ListClientsQuery {
+            app_type: Some("spa,non_interactive".to_string()),
+            is_global: Some(false),
+            ..Default::default()
+        };
+
+        let result = list_clients(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let clients = result.unwrap();
+        assert_eq!(clients.len(), 2);
+        assert_eq!(clients[1].client_id, "client_m2m");
+    }
+
+    #[tokio::test]
+    async fn test_list_clients_unauthorized() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("invalid-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/clients")
+            .with_status(401)
+            .with_body(r#"{"statusCode": 401, "message": "Invalid token"}"#)
+            .create_async()
+            .await;
+
+        let result = list_clients(&domain, &token, Default::default()).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Unauthorized(_))));
+    }
+}
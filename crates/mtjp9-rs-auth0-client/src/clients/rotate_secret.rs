@@ -0,0 +1,127 @@
+//! Client secret rotation for Auth0 Management API v2
+//!
+//! This module provides the `rotate_secret` function for rotating an application's client
+//! secret. It wraps the POST /api/v2/clients/{id}/rotate-secret endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/post-rotate-secret)
+//! for more details about the rotate secret endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_client::ClientResponse;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Rotates a client's secret.
+///
+/// The previous secret stops working immediately. The returned client contains the new
+/// `client_secret`.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:client_keys` scope
+/// * `client_id` - The ID of the client whose secret should be rotated
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The client ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn rotate_secret(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+) -> Result<ClientResponse> {
+    // Validate client_id
+    if client_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and client ID
+    let endpoint = domain.to_url(&format!("/api/v2/clients/{client_id}/rotate-secret"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to rotate the secret
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => response
+            .json::<ClientResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_rotate_secret_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/clients/client_abc/rotate-secret")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"client_id": "client_abc", "name": "M2M", "client_secret": "new-secret"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = rotate_secret(&domain, &token, "client_abc").await;
+        mock.assert_async().await;
+
+        assert_eq!(
+            result.unwrap().client_secret,
+            Some("new-secret".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rotate_secret_forbidden() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/clients/client_abc/rotate-secret")
+            .with_status(403)
+            .with_body(r#"{"statusCode": 403, "message": "Insufficient scope"}"#)
+            .create_async()
+            .await;
+
+        let result = rotate_secret(&domain, &token, "client_abc").await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Forbidden(_))));
+    }
+}
@@ -0,0 +1,198 @@
+//! Client (application) update functionality for Auth0 Management API v2
+//!
+//! This module provides the `update_client` function for updating an existing application.
+//! It wraps the PATCH /api/v2/clients/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/clients/patch-clients-by-id)
+//! for more details about the client update endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_client::{
+    AppType, ClientAuthenticationMethods, ClientResponse, JwtConfiguration,
+    OrganizationRequireBehavior, OrganizationUsage, TokenEndpointAuthMethod,
+};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating an existing client
+///
+/// All fields are optional. Only fields that are provided will be updated.
+/// List fields such as `callbacks` replace the existing list entirely.
+///
+/// See: <https://auth0.com/docs/api/management/v2/clients/patch-clients-by-id>
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateClientRequest {
+    /// The name of the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Free text description of the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The type of application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<AppType>,
+
+    /// Whether this is a first party client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_party: Option<bool>,
+
+    /// Allowed callback URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callbacks: Option<Vec<String>>,
+
+    /// Allowed URLs to redirect to after logout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+
+    /// Allowed origins for cross-origin authentication and web message response mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_origins: Option<Vec<String>>,
+
+    /// Allowed origins for CORS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+
+    /// Grant types the client may use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_types: Option<Vec<String>>,
+
+    /// How the client authenticates at the token endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<TokenEndpointAuthMethod>,
+
+    /// Alternative client authentication methods such as `private_key_jwt`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_authentication_methods: Option<ClientAuthenticationMethods>,
+
+    /// JWT settings for tokens issued to the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_configuration: Option<JwtConfiguration>,
+
+    /// Whether the client can be used with organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_usage: Option<OrganizationUsage>,
+
+    /// How the login flow behaves when an organization is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_require_behavior: Option<OrganizationRequireBehavior>,
+
+    /// Metadata associated with the client (string values only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_metadata: Option<serde_json::Value>,
+}
+
+/// Updates an existing client (application) in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:clients` scope
+/// * `client_id` - The ID of the client to update
+/// * `request` - The client fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The client ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_client(
+    domain: &Domain,
+    token: &BearerToken,
+    client_id: &str,
+    request: UpdateClientRequest,
+) -> Result<ClientResponse> {
+    // Validate client_id
+    if client_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Client ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and client ID
+    let endpoint = domain.to_url(&format!("/api/v2/clients/{client_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the client
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ClientResponse>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_update_client_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UpdateClientRequest {
+            callbacks: Some(vec!["https://new.example.com/callback".to_string()]),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/clients/client_abc")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"callbacks": ["https://new.example.com/callback"]}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "client_id": "client_abc",
+                    "name": "My SPA",
+                    "callbacks": ["https://new.example.com/callback"]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_client(&domain, &token, "client_abc", request).await;
+        mock.assert_async().await;
+
+        let client = result.unwrap();
+        assert_eq!(client.callbacks, vec!["https://new.example.com/callback"]);
+    }
+
+    #[test]
+    fn test_update_client_request_empty_serialization() {
+        let request = UpdateClientRequest::default();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json, serde_json::json!({}));
+    }
+}
@@ -0,0 +1,32 @@
+//! Connection management functionality for Auth0
+//!
+//! This module provides functions for managing identity provider connections through the
+//! Auth0 Management API v2.
+
+mod create_connection;
+mod delete_connection;
+mod delete_users_by_email;
+mod enterprise;
+mod get_connection;
+mod get_connection_status;
+mod list_connections;
+mod update_connection;
+
+pub use create_connection::{
+    create_connection, Connection, ConnectionOptions, CreateConnectionRequest,
+    DatabaseConnectionOptions, PasswordComplexityOptions, PasswordDictionary, PasswordHistory,
+    PasswordNoPersonalInfo, PasswordPolicy, DATABASE_STRATEGY,
+};
+pub use delete_connection::delete_connection;
+pub use delete_users_by_email::delete_users_by_email;
+pub use enterprise::{
+    AttributeMap, EnterpriseOptions, MappingMode, OidcConnectionOptions,
+    OidcConnectionOptionsBuilder, OidcConnectionType, OktaConnectionOptions,
+    OktaConnectionOptionsBuilder, SamlpConnectionOptions, SamlpConnectionOptionsBuilder,
+    WaadConnectionOptions, WaadConnectionOptionsBuilder, OIDC_STRATEGY, OKTA_STRATEGY,
+    SAMLP_STRATEGY, WAAD_STRATEGY,
+};
+pub use get_connection::get_connection;
+pub use get_connection_status::{get_connection_status, ConnectionStatus};
+pub use list_connections::{list_connections, ListConnectionsQuery};
+pub use update_connection::{update_connection, UpdateConnectionRequest};
@@ -0,0 +1,552 @@
+//! Connection creation functionality for Auth0 Management API v2
+//!
+//! This module provides the `create_connection` function for creating a new connection
+//! in Auth0. It wraps the POST /api/v2/connections endpoint.
+//!
+//! Connection `options` are strongly typed for the `auth0` database strategy and kept as
+//! a raw JSON value for every other strategy.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     connections::{
+//!         ConnectionOptions, CreateConnectionRequest, DatabaseConnectionOptions,
+//!         PasswordPolicy, create_connection,
+//!     },
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let options = DatabaseConnectionOptions {
+//!         password_policy: Some(PasswordPolicy::Good),
+//!         requires_username: Some(false),
+//!         disable_signup: Some(true),
+//!         brute_force_protection: Some(true),
+//!         ..Default::default()
+//!     };
+//!
+//!     let request = CreateConnectionRequest {
+//!         name: "customers-db".to_string(),
+//!         strategy: "auth0".to_string(),
+//!         options: Some(ConnectionOptions::Database(Box::new(options))),
+//!         ..Default::default()
+//!     };
+//!
+//!     let connection = create_connection(&domain, &token, request).await?;
+//!     println!("Created connection {} ({})", connection.name, connection.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/post-connections)
+//! for more details about the connection creation endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::enterprise::{
+    EnterpriseOptions, OidcConnectionOptions, OktaConnectionOptions, SamlpConnectionOptions,
+    WaadConnectionOptions, OIDC_STRATEGY, OKTA_STRATEGY, SAMLP_STRATEGY, WAAD_STRATEGY,
+};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Strategy name of Auth0 hosted database connections
+pub const DATABASE_STRATEGY: &str = "auth0";
+
+/// Password strength policy of a database connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordPolicy {
+    None,
+    Low,
+    Fair,
+    Good,
+    Excellent,
+}
+
+/// Password complexity options of a database connection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PasswordComplexityOptions {
+    /// Minimum password length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+}
+
+/// Password history settings of a database connection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PasswordHistory {
+    /// Whether previously used passwords are rejected
+    pub enable: bool,
+
+    /// Number of previous passwords to remember
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+/// Settings that reject passwords containing the user's personal data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PasswordNoPersonalInfo {
+    /// Whether the check is enabled
+    pub enable: bool,
+}
+
+/// Password dictionary settings of a database connection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PasswordDictionary {
+    /// Whether passwords from the dictionary are rejected
+    pub enable: bool,
+
+    /// Additional words to reject
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dictionary: Vec<String>,
+}
+
+/// Options of an `auth0` database connection
+///
+/// Options not modelled here are preserved in `extra`, so a fetched options object can be
+/// modified and sent back without losing settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DatabaseConnectionOptions {
+    /// Password strength policy
+    #[serde(rename = "passwordPolicy", skip_serializing_if = "Option::is_none")]
+    pub password_policy: Option<PasswordPolicy>,
+
+    /// Password complexity options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_complexity_options: Option<PasswordComplexityOptions>,
+
+    /// Password history settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_history: Option<PasswordHistory>,
+
+    /// Personal data check settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_no_personal_info: Option<PasswordNoPersonalInfo>,
+
+    /// Password dictionary settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_dictionary: Option<PasswordDictionary>,
+
+    /// Whether users must provide a username in addition to their email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_username: Option<bool>,
+
+    /// Whether public sign ups are disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_signup: Option<bool>,
+
+    /// Whether brute force protection is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brute_force_protection: Option<bool>,
+
+    /// Any other options of the connection
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Options of a connection
+///
+/// When part of a [`Connection`], the variant is chosen from the connection's strategy.
+/// Deserializing options on their own always yields [`ConnectionOptions::Database`] for
+/// JSON objects, with unknown fields kept in `extra`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConnectionOptions {
+    /// Options of an `auth0` database connection
+    Database(Box<DatabaseConnectionOptions>),
+
+    /// Options of a `samlp` connection
+    Samlp(Box<SamlpConnectionOptions>),
+
+    /// Options of an `oidc` connection
+    Oidc(Box<OidcConnectionOptions>),
+
+    /// Options of an `okta` connection
+    Okta(Box<OktaConnectionOptions>),
+
+    /// Options of a `waad` connection
+    Waad(Box<WaadConnectionOptions>),
+
+    /// Options of any other strategy
+    Other(Value),
+}
+
+impl ConnectionOptions {
+    /// Parses raw options according to the connection strategy.
+    pub fn from_strategy(
+        strategy: &str,
+        value: Value,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        match strategy {
+            DATABASE_STRATEGY => Ok(Self::Database(Box::new(serde_json::from_value(value)?))),
+            SAMLP_STRATEGY => Ok(Self::Samlp(Box::new(serde_json::from_value(value)?))),
+            OIDC_STRATEGY => Ok(Self::Oidc(Box::new(serde_json::from_value(value)?))),
+            OKTA_STRATEGY => Ok(Self::Okta(Box::new(serde_json::from_value(value)?))),
+            WAAD_STRATEGY => Ok(Self::Waad(Box::new(serde_json::from_value(value)?))),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+
+    /// Checks the required fields of typed enterprise options.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Samlp(options) => options.validate(),
+            Self::Oidc(options) => options.validate(),
+            Self::Okta(options) => options.validate(),
+            Self::Waad(options) => options.validate(),
+            Self::Database(_) | Self::Other(_) => Ok(()),
+        }
+    }
+
+    /// Returns the database options, if this is a database connection.
+    pub fn as_database(&self) -> Option<&DatabaseConnectionOptions> {
+        match self {
+            Self::Database(options) => Some(options),
+            _ => None,
+        }
+    }
+}
+
+/// Request body for creating a connection
+///
+/// See: <https://auth0.com/docs/api/management/v2/connections/post-connections>
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateConnectionRequest {
+    /// The name of the connection. Must be unique within the tenant.
+    pub name: String,
+
+    /// The identity provider strategy (e.g. "auth0", "google-oauth2", "samlp")
+    pub strategy: String,
+
+    /// The name shown on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Strategy specific options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ConnectionOptions>,
+
+    /// IDs of the clients for which the connection is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+
+    /// Realms for which the connection will be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realms: Option<Vec<String>>,
+
+    /// Whether the connection is a domain level connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+
+    /// Whether the connection is shown as a button on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as_button: Option<bool>,
+
+    /// Metadata associated with the connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateConnectionRequest {
+    /// Creates a request for an enterprise connection, using the strategy of the options.
+    pub fn enterprise<O: EnterpriseOptions>(name: impl Into<String>, options: O) -> Self {
+        Self {
+            name: name.into(),
+            strategy: O::STRATEGY.to_string(),
+            options: Some(options.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A connection as returned by the Management API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawConnection")]
+pub struct Connection {
+    /// The connection's unique identifier
+    pub id: String,
+
+    /// The name of the connection
+    pub name: String,
+
+    /// The name shown on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// The identity provider strategy
+    pub strategy: String,
+
+    /// Strategy specific options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ConnectionOptions>,
+
+    /// IDs of the clients for which the connection is enabled
+    #[serde(default)]
+    pub enabled_clients: Vec<String>,
+
+    /// Realms for which the connection will be used
+    #[serde(default)]
+    pub realms: Vec<String>,
+
+    /// Whether the connection is a domain level connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+
+    /// Whether the connection is shown as a button on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as_button: Option<bool>,
+
+    /// Metadata associated with the connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Wire representation of a connection, before its options are typed by strategy
+#[derive(Deserialize)]
+struct RawConnection {
+    id: String,
+    name: String,
+    display_name: Option<String>,
+    strategy: String,
+    options: Option<Value>,
+    #[serde(default)]
+    enabled_clients: Vec<String>,
+    #[serde(default)]
+    realms: Vec<String>,
+    is_domain_connection: Option<bool>,
+    show_as_button: Option<bool>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl From<RawConnection> for Connection {
+    fn from(raw: RawConnection) -> Self {
+        // Options that do not match their typed model are kept raw, so one unusual
+        // connection does not fail a whole listing
+        let options = raw.options.map(|value| {
+            ConnectionOptions::from_strategy(&raw.strategy, value.clone())
+                .unwrap_or(ConnectionOptions::Other(value))
+        });
+
+        Self {
+            id: raw.id,
+            name: raw.name,
+            display_name: raw.display_name,
+            strategy: raw.strategy,
+            options,
+            enabled_clients: raw.enabled_clients,
+            realms: raw.realms,
+            is_domain_connection: raw.is_domain_connection,
+            show_as_button: raw.show_as_button,
+            metadata: raw.metadata,
+        }
+    }
+}
+
+/// Creates a connection in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:connections` scope
+/// * `request` - The connection configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The name or strategy is empty
+/// * Typed enterprise options are missing required fields
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn create_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateConnectionRequest,
+) -> Result<Connection> {
+    // Validate name and strategy
+    if request.name.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection name cannot be empty".to_string(),
+        ));
+    }
+    if request.strategy.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection strategy cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate typed options
+    if let Some(options) = &request.options {
+        options.validate()?;
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/connections");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the connection
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<Connection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_create_database_connection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let options = DatabaseConnectionOptions {
+            password_policy: Some(PasswordPolicy::Good),
+            password_complexity_options: Some(PasswordComplexityOptions {
+                min_length: Some(12),
+            }),
+            disable_signup: Some(true),
+            ..Default::default()
+        };
+        let request = CreateConnectionRequest {
+            name: "customers-db".to_string(),
+            strategy: DATABASE_STRATEGY.to_string(),
+            options: Some(ConnectionOptions::Database(Box::new(options))),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/connections")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{
+                    "name": "customers-db",
+                    "strategy": "auth0",
+                    "options": {
+                        "passwordPolicy": "good",
+                        "password_complexity_options": {"min_length": 12},
+                        "disable_signup": true
+                    }
+                }"#
+                .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "con_1",
+                    "name": "customers-db",
+                    "strategy": "auth0",
+                    "options": {
+                        "passwordPolicy": "good",
+                        "password_complexity_options": {"min_length": 12},
+                        "disable_signup": true,
+                        "import_mode": false
+                    },
+                    "realms": ["customers-db"]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = create_connection(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let connection = result.unwrap();
+        let options = connection
+            .options
+            .as_ref()
+            .and_then(|o| o.as_database())
+            .unwrap();
+        assert_eq!(options.password_policy, Some(PasswordPolicy::Good));
+        assert_eq!(options.extra.get("import_mode"), Some(&Value::Bool(false)));
+    }
+
+    #[tokio::test]
+    async fn test_create_connection_empty_name() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateConnectionRequest {
+            strategy: DATABASE_STRATEGY.to_string(),
+            ..Default::default()
+        };
+
+        let result = create_connection(&domain, &token, request).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_connection_invalid_enterprise_options() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        // Built without the builder, so required fields were never checked
+        let options = crate::connections::OktaConnectionOptions {
+            domain: Some("acme.okta.com".to_string()),
+            ..Default::default()
+        };
+        let request = CreateConnectionRequest::enterprise("acme-okta", options);
+        assert_eq!(request.strategy, "okta");
+
+        let result = create_connection(&domain, &token, request).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_connection_options_typed_by_strategy() {
+        let connection: Connection = serde_json::from_str(
+            r#"{
+                "id": "con_2",
+                "name": "google-oauth2",
+                "strategy": "google-oauth2",
+                "options": {"client_id": "abc", "scope": ["email"]}
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            connection.options,
+            Some(ConnectionOptions::Other(_))
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+//! Connection deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_connection` function for deleting a connection.
+//! It wraps the DELETE /api/v2/connections/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/delete-connections-by-id)
+//! for more details about the delete connection endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes a connection and all of its users.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:connections` scope
+/// * `connection_id` - The ID of the connection to delete
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The connection ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    connection_id: &str,
+) -> Result<()> {
+    // Validate connection ID
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and connection ID
+    let endpoint = domain.to_url(&format!("/api/v2/connections/{connection_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the connection
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code. Deletion is processed asynchronously
+    // by Auth0 and answered with 202 Accepted.
+    match response.status() {
+        StatusCode::ACCEPTED | StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_delete_connection_accepted() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/connections/con_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(202)
+            .create_async()
+            .await;
+
+        let result = delete_connection(&domain, &token, "con_1").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_connection_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = delete_connection(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,138 @@
+//! Database connection user deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_users_by_email` function for removing a user from a
+//! database connection by email. It wraps the DELETE /api/v2/connections/{id}/users endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     connections::delete_users_by_email,
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Remove a test account from the dedicated test connection
+//!     delete_users_by_email(&domain, &token, "con_test", "e2e+1234@example.com").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/delete-users-by-email)
+//! for more details about the delete users by email endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes the user with the given email from a database connection.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:users` scope
+/// * `connection_id` - The ID of the database connection
+/// * `email` - The email of the user to delete
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The connection ID or email is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_users_by_email(
+    domain: &Domain,
+    token: &BearerToken,
+    connection_id: &str,
+    email: &str,
+) -> Result<()> {
+    // Validate connection ID and email
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+    if email.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Email cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and connection ID
+    let endpoint = domain.to_url(&format!("/api/v2/connections/{connection_id}/users"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the user
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&[("email", email)])
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_delete_users_by_email_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/connections/con_test/users")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::UrlEncoded(
+                "email".into(),
+                "e2e+1234@example.com".into(),
+            ))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result =
+            delete_users_by_email(&domain, &token, "con_test", "e2e+1234@example.com").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_users_by_email_empty_email() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = delete_users_by_email(&domain, &token, "con_test", "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,705 @@
+//! Enterprise connection options for Auth0 Management API v2
+//!
+//! This module provides typed options and builders for the `samlp`, `oidc`, `okta` and
+//! `waad` (Microsoft Azure AD) strategies. Builders validate the fields each strategy
+//! requires, so an incomplete configuration is rejected before any request is sent.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     connections::{CreateConnectionRequest, SamlpConnectionOptions, create_connection},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let options = SamlpConnectionOptions::builder()
+//!         .metadata_url("https://idp.acme.com/saml/metadata")
+//!         .domain_alias("acme.com")
+//!         .field_mapping("email", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress")
+//!         .build()?;
+//!
+//!     let request = CreateConnectionRequest::enterprise("acme-saml", options);
+//!     let connection = create_connection(&domain, &token, request).await?;
+//!     println!("Created connection {}", connection.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 documentation](https://auth0.com/docs/authenticate/identity-providers/enterprise-identity-providers)
+//! for more details about enterprise connections.
+
+use crate::error::{Auth0Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::create_connection::ConnectionOptions;
+
+/// Strategy name of SAML connections
+pub const SAMLP_STRATEGY: &str = "samlp";
+
+/// Strategy name of OpenID Connect connections
+pub const OIDC_STRATEGY: &str = "oidc";
+
+/// Strategy name of Okta Workforce connections
+pub const OKTA_STRATEGY: &str = "okta";
+
+/// Strategy name of Microsoft Azure AD connections
+pub const WAAD_STRATEGY: &str = "waad";
+
+/// Options type that belongs to a single enterprise strategy
+pub trait EnterpriseOptions: Into<ConnectionOptions> {
+    /// The strategy these options are used with
+    const STRATEGY: &'static str;
+}
+
+fn require(value: &Option<String>, message: &str) -> Result<()> {
+    match value {
+        Some(v) if !v.is_empty() => Ok(()),
+        _ => Err(Auth0Error::InvalidRequest(message.to_string())),
+    }
+}
+
+fn require_https(value: &Option<String>, name: &str) -> Result<()> {
+    match value {
+        Some(url) if !url.starts_with("https://") => Err(Auth0Error::InvalidRequest(format!(
+            "{name} must be an https URL"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// How attributes returned by an OpenID Connect provider are mapped to the user profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MappingMode {
+    /// Only use the mappings in `attributes`
+    UseMap,
+
+    /// Map the standard OIDC profile claims
+    BasicProfile,
+
+    /// Copy every claim to the user profile
+    BindAll,
+}
+
+/// Attribute mapping of OIDC and Okta connections
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeMap {
+    /// The mapping mode
+    pub mapping_mode: MappingMode,
+
+    /// Scopes requested from the userinfo endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userinfo_scope: Option<String>,
+
+    /// Map of user profile attribute to provider claim (e.g. `"given_name": "${context.tokenset.first_name}"`)
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub attributes: Map<String, Value>,
+}
+
+/// Options of a `samlp` connection
+///
+/// The identity provider is described either by its metadata (`metadata_url` or
+/// `metadata_xml`) or by its sign in endpoint together with its signing certificate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SamlpConnectionOptions {
+    /// URL of the identity provider's SSO endpoint
+    #[serde(rename = "signInEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_in_endpoint: Option<String>,
+
+    /// URL of the identity provider's logout endpoint
+    #[serde(rename = "signOutEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_out_endpoint: Option<String>,
+
+    /// Base64 encoded X.509 signing certificate of the identity provider (PEM or CER)
+    #[serde(rename = "signingCert", skip_serializing_if = "Option::is_none")]
+    pub signing_cert: Option<String>,
+
+    /// URL of the identity provider's SAML metadata document
+    #[serde(rename = "metadataUrl", skip_serializing_if = "Option::is_none")]
+    pub metadata_url: Option<String>,
+
+    /// The identity provider's SAML metadata document
+    #[serde(rename = "metadataXml", skip_serializing_if = "Option::is_none")]
+    pub metadata_xml: Option<String>,
+
+    /// Algorithm used to sign SAML requests (e.g. "rsa-sha256")
+    #[serde(rename = "signatureAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub signature_algorithm: Option<String>,
+
+    /// Digest algorithm used to sign SAML requests (e.g. "sha256")
+    #[serde(rename = "digestAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub digest_algorithm: Option<String>,
+
+    /// Email domains routed to this connection by Home Realm Discovery
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_aliases: Vec<String>,
+
+    /// Map of user profile attribute to SAML assertion attribute
+    #[serde(rename = "fieldsMap", default, skip_serializing_if = "Map::is_empty")]
+    pub fields_map: Map<String, Value>,
+
+    /// Any other options of the connection
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SamlpConnectionOptions {
+    pub fn builder() -> SamlpConnectionOptionsBuilder {
+        SamlpConnectionOptionsBuilder::default()
+    }
+
+    /// Checks that the options describe the identity provider completely.
+    pub fn validate(&self) -> Result<()> {
+        let has_metadata = self.metadata_url.is_some() || self.metadata_xml.is_some();
+        if !has_metadata {
+            require(
+                &self.sign_in_endpoint,
+                "SAML sign in endpoint is required when no metadata is provided",
+            )?;
+            require(
+                &self.signing_cert,
+                "SAML signing certificate is required when no metadata is provided",
+            )?;
+        }
+        require_https(&self.sign_in_endpoint, "SAML sign in endpoint")?;
+        require_https(&self.metadata_url, "SAML metadata URL")?;
+        Ok(())
+    }
+}
+
+impl EnterpriseOptions for SamlpConnectionOptions {
+    const STRATEGY: &'static str = SAMLP_STRATEGY;
+}
+
+impl From<SamlpConnectionOptions> for ConnectionOptions {
+    fn from(options: SamlpConnectionOptions) -> Self {
+        ConnectionOptions::Samlp(Box::new(options))
+    }
+}
+
+#[derive(Default)]
+pub struct SamlpConnectionOptionsBuilder {
+    options: SamlpConnectionOptions,
+}
+
+impl SamlpConnectionOptionsBuilder {
+    pub fn sign_in_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.options.sign_in_endpoint = Some(url.into());
+        self
+    }
+
+    pub fn sign_out_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.options.sign_out_endpoint = Some(url.into());
+        self
+    }
+
+    pub fn signing_cert(mut self, cert: impl Into<String>) -> Self {
+        self.options.signing_cert = Some(cert.into());
+        self
+    }
+
+    pub fn metadata_url(mut self, url: impl Into<String>) -> Self {
+        self.options.metadata_url = Some(url.into());
+        self
+    }
+
+    pub fn metadata_xml(mut self, xml: impl Into<String>) -> Self {
+        self.options.metadata_xml = Some(xml.into());
+        self
+    }
+
+    pub fn signature_algorithm(mut self, algorithm: impl Into<String>) -> Self {
+        self.options.signature_algorithm = Some(algorithm.into());
+        self
+    }
+
+    pub fn digest_algorithm(mut self, algorithm: impl Into<String>) -> Self {
+        self.options.digest_algorithm = Some(algorithm.into());
+        self
+    }
+
+    pub fn domain_alias(mut self, domain: impl Into<String>) -> Self {
+        self.options.domain_aliases.push(domain.into());
+        self
+    }
+
+    pub fn field_mapping(
+        mut self,
+        attribute: impl Into<String>,
+        assertion: impl Into<String>,
+    ) -> Self {
+        self.options
+            .fields_map
+            .insert(attribute.into(), Value::String(assertion.into()));
+        self
+    }
+
+    pub fn build(self) -> Result<SamlpConnectionOptions> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+/// Channel used by an OpenID Connect connection to obtain tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OidcConnectionType {
+    /// Authorization code flow; requires a client secret
+    BackChannel,
+
+    /// Implicit flow with `form_post` response mode
+    FrontChannel,
+}
+
+/// Options of an `oidc` connection
+///
+/// The provider is described either by its `discovery_url` or by its `issuer` together
+/// with the individual endpoints.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OidcConnectionOptions {
+    /// Channel used to obtain tokens
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub connection_type: Option<OidcConnectionType>,
+
+    /// URL of the provider's `.well-known/openid-configuration` document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discovery_url: Option<String>,
+
+    /// Issuer identifier of the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+
+    /// URL of the provider's authorization endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_endpoint: Option<String>,
+
+    /// URL of the provider's token endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint: Option<String>,
+
+    /// URL of the provider's JSON Web Key Set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks_uri: Option<String>,
+
+    /// Client ID registered with the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Client secret registered with the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Space separated scopes requested from the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// Email domains routed to this connection by Home Realm Discovery
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_aliases: Vec<String>,
+
+    /// Mapping of provider claims to the user profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_map: Option<AttributeMap>,
+
+    /// Any other options of the connection
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl OidcConnectionOptions {
+    pub fn builder() -> OidcConnectionOptionsBuilder {
+        OidcConnectionOptionsBuilder::default()
+    }
+
+    /// Checks that the options describe the provider completely.
+    pub fn validate(&self) -> Result<()> {
+        require(&self.client_id, "OIDC client ID is required")?;
+        if self.discovery_url.is_none() {
+            require(&self.issuer, "OIDC discovery URL or issuer is required")?;
+        }
+        if self.connection_type != Some(OidcConnectionType::FrontChannel) {
+            require(
+                &self.client_secret,
+                "OIDC client secret is required for back channel connections",
+            )?;
+        }
+        require_https(&self.discovery_url, "OIDC discovery URL")?;
+        Ok(())
+    }
+}
+
+impl EnterpriseOptions for OidcConnectionOptions {
+    const STRATEGY: &'static str = OIDC_STRATEGY;
+}
+
+impl From<OidcConnectionOptions> for ConnectionOptions {
+    fn from(options: OidcConnectionOptions) -> Self {
+        ConnectionOptions::Oidc(Box::new(options))
+    }
+}
+
+#[derive(Default)]
+pub struct OidcConnectionOptionsBuilder {
+    options: OidcConnectionOptions,
+}
+
+impl OidcConnectionOptionsBuilder {
+    pub fn connection_type(mut self, connection_type: OidcConnectionType) -> Self {
+        self.options.connection_type = Some(connection_type);
+        self
+    }
+
+    pub fn discovery_url(mut self, url: impl Into<String>) -> Self {
+        self.options.discovery_url = Some(url.into());
+        self
+    }
+
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.options.issuer = Some(issuer.into());
+        self
+    }
+
+    pub fn authorization_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.options.authorization_endpoint = Some(url.into());
+        self
+    }
+
+    pub fn token_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.options.token_endpoint = Some(url.into());
+        self
+    }
+
+    pub fn jwks_uri(mut self, url: impl Into<String>) -> Self {
+        self.options.jwks_uri = Some(url.into());
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.options.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.options.client_secret = Some(client_secret.into());
+        self
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.options.scope = Some(scope.into());
+        self
+    }
+
+    pub fn domain_alias(mut self, domain: impl Into<String>) -> Self {
+        self.options.domain_aliases.push(domain.into());
+        self
+    }
+
+    pub fn attribute_map(mut self, attribute_map: AttributeMap) -> Self {
+        self.options.attribute_map = Some(attribute_map);
+        self
+    }
+
+    pub fn build(self) -> Result<OidcConnectionOptions> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+/// Options of an `okta` connection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct OktaConnectionOptions {
+    /// The Okta domain (e.g. "acme.okta.com")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Client ID of the Okta application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Client secret of the Okta application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Space separated scopes requested from Okta
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// Email domains routed to this connection by Home Realm Discovery
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_aliases: Vec<String>,
+
+    /// Mapping of Okta claims to the user profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_map: Option<AttributeMap>,
+
+    /// Any other options of the connection
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl OktaConnectionOptions {
+    pub fn builder() -> OktaConnectionOptionsBuilder {
+        OktaConnectionOptionsBuilder::default()
+    }
+
+    /// Checks that the Okta domain and application credentials are present.
+    pub fn validate(&self) -> Result<()> {
+        require(&self.domain, "Okta domain is required")?;
+        require(&self.client_id, "Okta client ID is required")?;
+        require(&self.client_secret, "Okta client secret is required")?;
+        Ok(())
+    }
+}
+
+impl EnterpriseOptions for OktaConnectionOptions {
+    const STRATEGY: &'static str = OKTA_STRATEGY;
+}
+
+impl From<OktaConnectionOptions> for ConnectionOptions {
+    fn from(options: OktaConnectionOptions) -> Self {
+        ConnectionOptions::Okta(Box::new(options))
+    }
+}
+
+#[derive(Default)]
+pub struct OktaConnectionOptionsBuilder {
+    options: OktaConnectionOptions,
+}
+
+impl OktaConnectionOptionsBuilder {
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.options.domain = Some(domain.into());
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.options.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.options.client_secret = Some(client_secret.into());
+        self
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.options.scope = Some(scope.into());
+        self
+    }
+
+    pub fn domain_alias(mut self, domain: impl Into<String>) -> Self {
+        self.options.domain_aliases.push(domain.into());
+        self
+    }
+
+    pub fn attribute_map(mut self, attribute_map: AttributeMap) -> Self {
+        self.options.attribute_map = Some(attribute_map);
+        self
+    }
+
+    pub fn build(self) -> Result<OktaConnectionOptions> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+/// Options of a `waad` (Microsoft Azure AD) connection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct WaadConnectionOptions {
+    /// The Azure AD tenant domain (e.g. "acme.onmicrosoft.com")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_domain: Option<String>,
+
+    /// Client ID of the Azure AD application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Client secret of the Azure AD application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Email domains routed to this connection by Home Realm Discovery
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_aliases: Vec<String>,
+
+    /// Whether the user's groups are included in the profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_groups: Option<bool>,
+
+    /// Any other options of the connection
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl WaadConnectionOptions {
+    pub fn builder() -> WaadConnectionOptionsBuilder {
+        WaadConnectionOptionsBuilder::default()
+    }
+
+    /// Checks that the tenant domain and application credentials are present.
+    pub fn validate(&self) -> Result<()> {
+        require(&self.tenant_domain, "Azure AD tenant domain is required")?;
+        require(&self.client_id, "Azure AD client ID is required")?;
+        require(&self.client_secret, "Azure AD client secret is required")?;
+        Ok(())
+    }
+}
+
+impl EnterpriseOptions for WaadConnectionOptions {
+    const STRATEGY: &'static str = WAAD_STRATEGY;
+}
+
+impl From<WaadConnectionOptions> for ConnectionOptions {
+    fn from(options: WaadConnectionOptions) -> Self {
+        ConnectionOptions::Waad(Box::new(options))
+    }
+}
+
+#[derive(Default)]
+pub struct WaadConnectionOptionsBuilder {
+    options: WaadConnectionOptions,
+}
+
+impl WaadConnectionOptionsBuilder {
+    pub fn tenant_domain(mut self, tenant_domain: impl Into<String>) -> Self {
+        self.options.tenant_domain = Some(tenant_domain.into());
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.options.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.options.client_secret = Some(client_secret.into());
+        self
+    }
+
+    pub fn domain_alias(mut self, domain: impl Into<String>) -> Self {
+        self.options.domain_aliases.push(domain.into());
+        self
+    }
+
+    pub fn ext_groups(mut self, ext_groups: bool) -> Self {
+        self.options.ext_groups = Some(ext_groups);
+        self
+    }
+
+    pub fn build(self) -> Result<WaadConnectionOptions> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connections::{Connection, CreateConnectionRequest};
+
+    #[test]
+    fn test_samlp_builder_with_metadata_url() {
+        let options = SamlpConnectionOptions::builder()
+            .metadata_url("https://idp.acme.com/metadata")
+            .domain_alias("acme.com")
+            .field_mapping("email", "mail")
+            .build()
+            .unwrap();
+
+        let request = CreateConnectionRequest::enterprise("acme-saml", options);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "name": "acme-saml",
+                "strategy": "samlp",
+                "options": {
+                    "metadataUrl": "https://idp.acme.com/metadata",
+                    "domain_aliases": ["acme.com"],
+                    "fieldsMap": {"email": "mail"}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_samlp_builder_requires_certificate() {
+        let result = SamlpConnectionOptions::builder()
+            .sign_in_endpoint("https://idp.acme.com/sso")
+            .build();
+
+        match result {
+            Err(Auth0Error::InvalidRequest(msg)) => assert!(msg.contains("signing certificate")),
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_oidc_builder_validation() {
+        let missing_secret = OidcConnectionOptions::builder()
+            .discovery_url("https://login.acme.com/.well-known/openid-configuration")
+            .client_id("abc")
+            .build();
+        assert!(matches!(missing_secret, Err(Auth0Error::InvalidRequest(_))));
+
+        let front_channel = OidcConnectionOptions::builder()
+            .connection_type(OidcConnectionType::FrontChannel)
+            .issuer("https://login.acme.com")
+            .client_id("abc")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&front_channel).unwrap()["type"],
+            "front_channel"
+        );
+    }
+
+    #[test]
+    fn test_okta_and_waad_builders_require_credentials() {
+        let okta = OktaConnectionOptions::builder()
+            .domain("acme.okta.com")
+            .client_id("abc")
+            .build();
+        assert!(matches!(okta, Err(Auth0Error::InvalidRequest(_))));
+
+        let waad = WaadConnectionOptions::builder()
+            .tenant_domain("acme.onmicrosoft.com")
+            .client_id("abc")
+            .client_secret("secret")
+            .build();
+        assert!(waad.is_ok());
+    }
+
+    #[test]
+    fn test_enterprise_options_typed_by_strategy() {
+        let connection: Connection = serde_json::from_str(
+            r#"{
+                "id": "con_1",
+                "name": "acme-okta",
+                "strategy": "okta",
+                "options": {"domain": "acme.okta.com", "client_id": "abc", "set_user_root_attributes": "on_each_login"}
+            }"#,
+        )
+        .unwrap();
+
+        match connection.options {
+            Some(ConnectionOptions::Okta(options)) => {
+                assert_eq!(options.domain.as_deref(), Some("acme.okta.com"));
+                assert!(options.extra.contains_key("set_user_root_attributes"));
+            }
+            other => panic!("Expected Okta options, got {other:?}"),
+        }
+    }
+}
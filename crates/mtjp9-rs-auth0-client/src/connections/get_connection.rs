@@ -0,0 +1,132 @@
+//! Connection retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_connection` function for fetching a single connection.
+//! It wraps the GET /api/v2/connections/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/get-connections-by-id)
+//! for more details about the get connection endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_connection::Connection;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches a connection by its ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:connections` scope
+/// * `connection_id` - The ID of the connection
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The connection ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    connection_id: &str,
+) -> Result<Connection> {
+    // Validate connection ID
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and connection ID
+    let endpoint = domain.to_url(&format!("/api/v2/connections/{connection_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the connection
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Connection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connections::PasswordPolicy;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_connection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/connections/con_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "con_1",
+                    "name": "customers-db",
+                    "strategy": "auth0",
+                    "options": {
+                        "passwordPolicy": "excellent",
+                        "password_history": {"enable": true, "size": 5},
+                        "requires_username": true,
+                        "brute_force_protection": true
+                    },
+                    "enabled_clients": ["client_1"]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_connection(&domain, &token, "con_1").await;
+        mock.assert_async().await;
+
+        let connection = result.unwrap();
+        let options = connection
+            .options
+            .as_ref()
+            .and_then(|o| o.as_database())
+            .unwrap();
+        assert_eq!(options.password_policy, Some(PasswordPolicy::Excellent));
+        assert_eq!(options.password_history.as_ref().unwrap().size, Some(5));
+        assert_eq!(options.requires_username, Some(true));
+        assert_eq!(connection.enabled_clients, vec!["client_1"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_connection_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_connection(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
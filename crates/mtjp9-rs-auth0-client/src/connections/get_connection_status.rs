@@ -0,0 +1,180 @@
+//! Connection status check for Auth0 Management API v2
+//!
+//! This module provides the `get_connection_status` function for checking whether an
+//! AD/LDAP or custom database connection is online. It wraps the
+//! GET /api/v2/connections/{id}/status endpoint, which answers 200 for an online
+//! connection and 404 when no connector is online.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/get-status)
+//! for more details about the connection status endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Whether a connection can currently authenticate users
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    Online,
+    /// No connector is online; holds the message returned by Auth0
+    Offline(String),
+}
+
+impl ConnectionStatus {
+    /// Returns true for `Online`.
+    pub fn is_online(&self) -> bool {
+        matches!(self, Self::Online)
+    }
+}
+
+/// Checks whether a connection is online.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:connections` scope
+/// * `connection_id` - The ID of the connection to check
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The connection ID is empty
+/// * The request fails due to network issues
+/// * The connection does not exist
+/// * The API returns another error response (4xx or 5xx status codes)
+pub async fn get_connection_status(
+    domain: &Domain,
+    token: &BearerToken,
+    connection_id: &str,
+) -> Result<ConnectionStatus> {
+    // Validate connection ID
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and connection ID
+    let endpoint = domain.to_url(&format!("/api/v2/connections/{connection_id}/status"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to check the connection status
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => Ok(ConnectionStatus::Online),
+        StatusCode::NOT_FOUND => {
+            let body = response.text().await.unwrap_or_default();
+            // A missing connection is also answered with 404
+            if body.contains("does not exist") {
+                Err(Auth0Error::UnexpectedResponse { status: 404, body })
+            } else {
+                Ok(ConnectionStatus::Offline(body))
+            }
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_connection_status_online() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/connections/con_ldap/status")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let result = get_connection_status(&domain, &token, "con_ldap").await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), ConnectionStatus::Online);
+    }
+
+    #[tokio::test]
+    async fn test_get_connection_status_offline() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/connections/con_ldap/status")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "No connectors online"}"#)
+            .create_async()
+            .await;
+
+        let result = get_connection_status(&domain, &token, "con_ldap").await;
+        mock.assert_async().await;
+
+        let status = result.unwrap();
+        assert!(!status.is_online());
+        assert!(
+            matches!(status, ConnectionStatus::Offline(message) if message.contains("No connectors online"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_connection_status_missing_connection() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/connections/con_missing/status")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "The connection does not exist"}"#)
+            .create_async()
+            .await;
+
+        let result = get_connection_status(&domain, &token, "con_missing").await;
+
+        assert!(matches!(
+            result,
+            Err(Auth0Error::UnexpectedResponse { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_connection_status_unauthorized() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/connections/con_ldap/status")
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let result = get_connection_status(&domain, &token, "con_ldap").await;
+
+        assert!(matches!(result, Err(Auth0Error::Unauthorized(_))));
+    }
+}
@@ -0,0 +1,225 @@
+//! Connection listing for Auth0 Management API v2
+//!
+//! This module provides the `list_connections` function for listing the connections of a
+//! tenant, optionally filtered by strategy or name. It wraps the GET /api/v2/connections
+//! endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     connections::{ListConnectionsQuery, list_connections, DATABASE_STRATEGY},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Discover the database connections users can be created in
+//!     let query = ListConnectionsQuery {
+//!         strategy: Some(DATABASE_STRATEGY.to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     for connection in list_connections(&domain, &token, query).await? {
+//!         println!("{}", connection.name);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/get-connections)
+//! for more details about the list connections endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_connection::Connection;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing connections
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListConnectionsQuery {
+    /// Only return connections using this strategy (e.g. "auth0")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+
+    /// Only return the connection with this name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Comma-separated list of fields to include or exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+
+    /// Whether the specified fields are included (true) or excluded (false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Lists the connections of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:connections` scope
+/// * `query` - Optional filters and pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_connections(
+    domain: &Domain,
+    token: &BearerToken,
+    query: ListConnectionsQuery,
+) -> Result<Vec<Connection>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/connections");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the connections
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<Connection>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connections::ConnectionOptions;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_connections_by_strategy() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/connections")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("strategy".into(), "auth0".into()),
+                Matcher::UrlEncoded("per_page".into(), "10".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "con_1", "name": "customers-db", "strategy": "auth0"},
+                    {"id": "con_2", "name": "staff-db", "strategy": "auth0"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let query = ListConnectionsQuery {
+            strategy: Some("auth0".to_string()),
+            per_page: Some(10),
+            ..Default::default()
+        };
+
+        let result = list_connections(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let names: Vec<_> = result.unwrap().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["customers-db", "staff-db"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_keeps_malformed_options_raw() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/connections")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "con_1", "name": "customers-db", "strategy": "auth0", "options": {"passwordPolicy": "good"}},
+                    {"id": "con_2", "name": "legacy-db", "strategy": "auth0", "options": {"passwordPolicy": 42}}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let connections = list_connections(&domain, &token, Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(connections.len(), 2);
+        assert!(connections[0]
+            .options
+            .as_ref()
+            .unwrap()
+            .as_database()
+            .is_some());
+        assert!(matches!(
+            &connections[1].options,
+            Some(ConnectionOptions::Other(value)) if value["passwordPolicy"] == 42
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_unauthorized() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/connections")
+            .with_status(401)
+            .with_body(r#"{"statusCode": 401, "message": "Invalid token"}"#)
+            .create_async()
+            .await;
+
+        let result = list_connections(&domain, &token, Default::default()).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Unauthorized(_))));
+    }
+}
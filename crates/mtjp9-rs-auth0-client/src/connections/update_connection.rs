@@ -0,0 +1,177 @@
+//! Connection update functionality for Auth0 Management API v2
+//!
+//! This module provides the `update_connection` function for updating an existing connection.
+//! It wraps the PATCH /api/v2/connections/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/connections/patch-connections-by-id)
+//! for more details about the connection update endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::create_connection::{Connection, ConnectionOptions};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating a connection
+///
+/// All fields are optional. Only fields that are provided will be updated.
+/// `options` replaces the connection's whole options object, so it should be based on the
+/// options returned by `get_connection`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateConnectionRequest {
+    /// The name shown on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Strategy specific options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ConnectionOptions>,
+
+    /// IDs of the clients for which the connection is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+
+    /// Realms for which the connection will be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realms: Option<Vec<String>>,
+
+    /// Whether the connection is a domain level connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+
+    /// Whether the connection is shown as a button on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as_button: Option<bool>,
+
+    /// Metadata associated with the connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Updates a connection in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:connections` scope
+/// * `connection_id` - The ID of the connection to update
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The connection ID is empty
+/// * Typed enterprise options are missing required fields
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    connection_id: &str,
+    request: UpdateConnectionRequest,
+) -> Result<Connection> {
+    // Validate connection ID
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate typed options
+    if let Some(options) = &request.options {
+        options.validate()?;
+    }
+
+    // Construct the API endpoint URL using the provided domain and connection ID
+    let endpoint = domain.to_url(&format!("/api/v2/connections/{connection_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the connection
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Connection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connections::DatabaseConnectionOptions;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_update_connection_options_round_trip() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        // Options fetched earlier, including a field that is not modelled
+        let mut options: DatabaseConnectionOptions =
+            serde_json::from_str(r#"{"disable_signup": false, "import_mode": true}"#).unwrap();
+        options.disable_signup = Some(true);
+
+        let request = UpdateConnectionRequest {
+            options: Some(ConnectionOptions::Database(Box::new(options))),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/connections/con_1")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"options": {"disable_signup": true, "import_mode": true}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "con_1",
+                    "name": "customers-db",
+                    "strategy": "auth0",
+                    "options": {"disable_signup": true, "import_mode": true}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_connection(&domain, &token, "con_1", request).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_connection_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = update_connection(&domain, &token, "", Default::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,23 @@
+//! Custom domain management functionality for Auth0
+//!
+//! This module provides functions for managing the custom domains of a tenant through the
+//! Auth0 Management API v2, including the DNS records that verify them and waiting for a
+//! domain to become ready.
+
+mod create_custom_domain;
+mod delete_custom_domain;
+mod get_custom_domain;
+mod list_custom_domains;
+mod update_custom_domain;
+mod verify_custom_domain;
+
+pub use create_custom_domain::{
+    create_custom_domain, ClientIpHeader, CreateCustomDomainRequest, CustomDomain,
+    CustomDomainStatus, CustomDomainType, DomainVerification, TlsPolicy, VerificationMethod,
+    VerificationMethodName,
+};
+pub use delete_custom_domain::delete_custom_domain;
+pub use get_custom_domain::get_custom_domain;
+pub use list_custom_domains::list_custom_domains;
+pub use update_custom_domain::{update_custom_domain, UpdateCustomDomainRequest};
+pub use verify_custom_domain::{verify_custom_domain, wait_for_custom_domain};
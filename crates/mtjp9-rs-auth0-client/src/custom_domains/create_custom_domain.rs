@@ -0,0 +1,511 @@
+//! Custom domain creation for Auth0 Management API v2
+//!
+//! This module provides the `create_custom_domain` function for adding a custom domain to
+//! the tenant. It wraps the POST /api/v2/custom-domains endpoint. The returned domain lists
+//! the DNS records that prove ownership; once they exist, the domain is verified with
+//! `verify_custom_domain`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     custom_domains::{CreateCustomDomainRequest, CustomDomainType, create_custom_domain},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateCustomDomainRequest::new("login.example.com", CustomDomainType::Auth0ManagedCerts);
+//!     let custom_domain = create_custom_domain(&domain, &token, request).await?;
+//!
+//!     println!("Create these DNS records for {}:", custom_domain.domain);
+//!     for method in custom_domain.verification_methods() {
+//!         println!("{:?} {} -> {}", method.name, method.domain.as_deref().unwrap_or(&custom_domain.domain), method.record);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/post-custom-domains)
+//! for more details about the create custom domain endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Who provisions the TLS certificate of a custom domain
+///
+/// Values not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CustomDomainType {
+    /// `auth0_managed_certs`: Auth0 provisions and renews the certificate
+    Auth0ManagedCerts,
+    /// `self_managed_certs`: a reverse proxy in front of Auth0 terminates TLS
+    SelfManagedCerts,
+    /// Any other certificate type
+    Other(String),
+}
+
+impl CustomDomainType {
+    /// The value Auth0 uses for this certificate type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Auth0ManagedCerts => "auth0_managed_certs",
+            Self::SelfManagedCerts => "self_managed_certs",
+            Self::Other(domain_type) => domain_type,
+        }
+    }
+}
+
+impl From<String> for CustomDomainType {
+    fn from(domain_type: String) -> Self {
+        match domain_type.as_str() {
+            "auth0_managed_certs" => Self::Auth0ManagedCerts,
+            "self_managed_certs" => Self::SelfManagedCerts,
+            _ => Self::Other(domain_type),
+        }
+    }
+}
+
+impl From<CustomDomainType> for String {
+    fn from(domain_type: CustomDomainType) -> Self {
+        match domain_type {
+            CustomDomainType::Other(domain_type) => domain_type,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CustomDomainType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Verification state of a custom domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainStatus {
+    /// Waiting for the DNS records
+    PendingVerification,
+    /// Verified, but the certificate is still being provisioned
+    Pending,
+    /// Serving traffic
+    Ready,
+    Disabled,
+    #[serde(other)]
+    Other,
+}
+
+/// TLS versions and ciphers accepted by the custom domain
+///
+/// Values not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TlsPolicy {
+    /// `recommended`: TLS 1.2 and later
+    Recommended,
+    /// `compatible`: also older TLS versions, for legacy clients
+    Compatible,
+    /// Any other policy
+    Other(String),
+}
+
+impl TlsPolicy {
+    /// The value Auth0 uses for this policy.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Recommended => "recommended",
+            Self::Compatible => "compatible",
+            Self::Other(policy) => policy,
+        }
+    }
+}
+
+impl From<String> for TlsPolicy {
+    fn from(policy: String) -> Self {
+        match policy.as_str() {
+            "recommended" => Self::Recommended,
+            "compatible" => Self::Compatible,
+            _ => Self::Other(policy),
+        }
+    }
+}
+
+impl From<TlsPolicy> for String {
+    fn from(policy: TlsPolicy) -> Self {
+        match policy {
+            TlsPolicy::Other(policy) => policy,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TlsPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// HTTP header a self-managed proxy puts the client IP address in
+///
+/// Values not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ClientIpHeader {
+    /// `true-client-ip`
+    TrueClientIp,
+    /// `cf-connecting-ip`, set by Cloudflare
+    CfConnectingIp,
+    /// `x-forwarded-for`
+    XForwardedFor,
+    /// `x-azure-clientip`, set by Azure Front Door
+    XAzureClientIp,
+    /// Use the address of the connection; sent to remove a configured header
+    None,
+    /// Any other header
+    Other(String),
+}
+
+impl ClientIpHeader {
+    /// The value Auth0 uses for this header.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TrueClientIp => "true-client-ip",
+            Self::CfConnectingIp => "cf-connecting-ip",
+            Self::XForwardedFor => "x-forwarded-for",
+            Self::XAzureClientIp => "x-azure-clientip",
+            Self::None => "",
+            Self::Other(header) => header,
+        }
+    }
+}
+
+impl From<String> for ClientIpHeader {
+    fn from(header: String) -> Self {
+        match header.as_str() {
+            "true-client-ip" => Self::TrueClientIp,
+            "cf-connecting-ip" => Self::CfConnectingIp,
+            "x-forwarded-for" => Self::XForwardedFor,
+            "x-azure-clientip" => Self::XAzureClientIp,
+            "" => Self::None,
+            _ => Self::Other(header),
+        }
+    }
+}
+
+impl From<ClientIpHeader> for String {
+    fn from(header: ClientIpHeader) -> Self {
+        match header {
+            ClientIpHeader::Other(header) => header,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ClientIpHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Kind of DNS record proving ownership of a custom domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationMethodName {
+    Cname,
+    Txt,
+}
+
+/// A DNS record to create for verifying a custom domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationMethod {
+    pub name: VerificationMethodName,
+
+    /// The value of the record
+    pub record: String,
+
+    /// The name of the record, if it differs from the custom domain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+}
+
+/// Verification details of a custom domain
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainVerification {
+    #[serde(default)]
+    pub methods: Vec<VerificationMethod>,
+}
+
+/// A custom domain of the tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDomain {
+    /// The custom domain's unique identifier
+    pub custom_domain_id: String,
+
+    /// The domain name, such as `login.example.com`
+    pub domain: String,
+
+    /// Whether this is the primary custom domain of the tenant
+    #[serde(default)]
+    pub primary: bool,
+
+    pub status: CustomDomainStatus,
+
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+
+    /// The Auth0 host a self-managed proxy forwards requests to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_domain_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<DomainVerification>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<ClientIpHeader>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+
+    /// The key a self-managed proxy sends in the `cname-api-key` header; only returned
+    /// when the domain is verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cname_api_key: Option<String>,
+}
+
+impl CustomDomain {
+    /// The DNS records to create for verifying the domain.
+    pub fn verification_methods(&self) -> &[VerificationMethod] {
+        self.verification
+            .as_ref()
+            .map_or(&[], |verification| verification.methods.as_slice())
+    }
+
+    /// Whether the domain is verified and serving traffic.
+    pub fn is_ready(&self) -> bool {
+        self.status == CustomDomainStatus::Ready
+    }
+}
+
+/// Request body for creating a custom domain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomDomainRequest {
+    pub domain: String,
+
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+
+    /// The kind of record proving ownership; Auth0 uses `Txt` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<VerificationMethodName>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+
+    /// Only for self-managed certificates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<ClientIpHeader>,
+}
+
+impl CreateCustomDomainRequest {
+    /// Creates a request for the domain name.
+    pub fn new(domain: impl Into<String>, domain_type: CustomDomainType) -> Self {
+        Self {
+            domain: domain.into(),
+            domain_type,
+            verification_method: None,
+            tls_policy: None,
+            custom_client_ip_header: None,
+        }
+    }
+}
+
+/// Adds a custom domain to the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:custom_domains` scope
+/// * `request` - The domain name and certificate type
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The domain name is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes), such as 409 Conflict
+///   when the domain already exists
+/// * The response cannot be deserialized
+pub async fn create_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateCustomDomainRequest,
+) -> Result<CustomDomain> {
+    // Validate domain name
+    if request.domain.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Custom domain name cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/custom-domains");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the custom domain
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<CustomDomain>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_create_custom_domain_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/custom-domains")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"domain": "login.example.com", "type": "auth0_managed_certs", "tls_policy": "recommended"}"#
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "custom_domain_id": "cd_1",
+                    "domain": "login.example.com",
+                    "primary": false,
+                    "status": "pending_verification",
+                    "type": "auth0_managed_certs",
+                    "verification": {
+                        "methods": [
+                            {"name": "cname", "record": "example-cd-1.edge.tenants.auth0.com"},
+                            {"name": "txt", "record": "auth0-domain-verification=abc", "domain": "_cf-custom-hostname.login.example.com"}
+                        ]
+                    },
+                    "tls_policy": "recommended"
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let request = CreateCustomDomainRequest {
+            tls_policy: Some(TlsPolicy::Recommended),
+            ..CreateCustomDomainRequest::new(
+                "login.example.com",
+                CustomDomainType::Auth0ManagedCerts,
+            )
+        };
+        let result = create_custom_domain(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let custom_domain = result.unwrap();
+        assert_eq!(
+            custom_domain.status,
+            CustomDomainStatus::PendingVerification
+        );
+        assert!(!custom_domain.is_ready());
+        let methods = custom_domain.verification_methods();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].name, VerificationMethodName::Cname);
+        assert_eq!(
+            methods[1].domain.as_deref(),
+            Some("_cf-custom-hostname.login.example.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_custom_domain_empty_name() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateCustomDomainRequest::new("", CustomDomainType::SelfManagedCerts);
+        let result = create_custom_domain(&domain, &token, request).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_client_ip_header_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ClientIpHeader::CfConnectingIp).unwrap(),
+            r#""cf-connecting-ip""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ClientIpHeader::None).unwrap(),
+            r#""""#
+        );
+    }
+
+    #[test]
+    fn test_unknown_values_are_kept() {
+        let custom_domain: CustomDomain = serde_json::from_str(
+            r#"{
+                "custom_domain_id": "cd_1",
+                "domain": "login.example.com",
+                "status": "ready",
+                "type": "partner_managed_certs",
+                "custom_client_ip_header": "fastly-client-ip",
+                "tls_policy": "modern"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            custom_domain.domain_type,
+            CustomDomainType::Other("partner_managed_certs".to_string())
+        );
+        assert_eq!(
+            custom_domain.custom_client_ip_header,
+            Some(ClientIpHeader::Other("fastly-client-ip".to_string()))
+        );
+        assert_eq!(
+            serde_json::to_value(&custom_domain).unwrap()["tls_policy"],
+            "modern"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Custom domain deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_custom_domain` function for removing a custom domain
+//! from the tenant. It wraps the DELETE /api/v2/custom-domains/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/delete-custom-domains-by-id)
+//! for more details about the delete custom domain endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes a custom domain. Logins through the domain stop working immediately.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:custom_domains` scope
+/// * `custom_domain_id` - The ID of the custom domain to delete
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The custom domain ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    custom_domain_id: &str,
+) -> Result<()> {
+    // Validate custom domain ID
+    if custom_domain_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Custom domain ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and custom domain ID
+    let endpoint = domain.to_url(&format!("/api/v2/custom-domains/{custom_domain_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the custom domain
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_delete_custom_domain_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/custom-domains/cd_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = delete_custom_domain(&domain, &token, "cd_1").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_custom_domain_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = delete_custom_domain(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,122 @@
+//! Custom domain retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_custom_domain` function for fetching a custom domain,
+//! including its verification records and status. It wraps the
+//! GET /api/v2/custom-domains/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/get-custom-domains-by-id)
+//! for more details about the get custom domain endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_custom_domain::CustomDomain;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Retrieves a custom domain by its ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:custom_domains` scope
+/// * `custom_domain_id` - The ID of the custom domain
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The custom domain ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    custom_domain_id: &str,
+) -> Result<CustomDomain> {
+    // Validate custom domain ID
+    if custom_domain_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Custom domain ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and custom domain ID
+    let endpoint = domain.to_url(&format!("/api/v2/custom-domains/{custom_domain_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the custom domain
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<CustomDomain>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_domains::CustomDomainStatus;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_custom_domain_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/custom-domains/cd_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "custom_domain_id": "cd_1",
+                    "domain": "login.example.com",
+                    "primary": false,
+                    "status": "pending",
+                    "type": "auth0_managed_certs",
+                    "verification": {"methods": []}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_custom_domain(&domain, &token, "cd_1").await;
+        mock.assert_async().await;
+
+        let custom_domain = result.unwrap();
+        assert_eq!(custom_domain.status, CustomDomainStatus::Pending);
+        assert!(custom_domain.verification_methods().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_custom_domain_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_custom_domain(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
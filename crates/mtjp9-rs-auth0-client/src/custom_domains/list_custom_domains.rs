@@ -0,0 +1,123 @@
+//! Custom domain listing for Auth0 Management API v2
+//!
+//! This module provides the `list_custom_domains` function for listing the custom domains of
+//! a tenant. It wraps the GET /api/v2/custom-domains endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/get-custom-domains)
+//! for more details about the list custom domains endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_custom_domain::CustomDomain;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Lists the custom domains of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:custom_domains` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_custom_domains(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<Vec<CustomDomain>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/custom-domains");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the custom domains
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<CustomDomain>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_domains::{ClientIpHeader, CustomDomainStatus, CustomDomainType};
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_list_custom_domains_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/custom-domains")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {
+                        "custom_domain_id": "cd_1",
+                        "domain": "login.example.com",
+                        "primary": true,
+                        "status": "ready",
+                        "type": "auth0_managed_certs",
+                        "tls_policy": "recommended"
+                    },
+                    {
+                        "custom_domain_id": "cd_2",
+                        "domain": "auth.example.org",
+                        "primary": false,
+                        "status": "ready",
+                        "type": "self_managed_certs",
+                        "origin_domain_name": "example-cd-2.edge.tenants.auth0.com",
+                        "custom_client_ip_header": "cf-connecting-ip"
+                    }
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_custom_domains(&domain, &token).await;
+        mock.assert_async().await;
+
+        let custom_domains = result.unwrap();
+        assert_eq!(custom_domains.len(), 2);
+        assert!(custom_domains[0].primary);
+        assert!(custom_domains[0].is_ready());
+        assert_eq!(
+            custom_domains[1].domain_type,
+            CustomDomainType::SelfManagedCerts
+        );
+        assert_eq!(custom_domains[1].status, CustomDomainStatus::Ready);
+        assert_eq!(
+            custom_domains[1].custom_client_ip_header,
+            Some(ClientIpHeader::CfConnectingIp)
+        );
+    }
+}
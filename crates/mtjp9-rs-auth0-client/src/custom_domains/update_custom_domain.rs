@@ -0,0 +1,146 @@
+//! Custom domain updates for Auth0 Management API v2
+//!
+//! This module provides the `update_custom_domain` function for changing the TLS policy and
+//! client IP header of a custom domain. It wraps the PATCH /api/v2/custom-domains/{id}
+//! endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/patch-custom-domains-by-id)
+//! for more details about the update custom domain endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_custom_domain::{ClientIpHeader, CustomDomain, TlsPolicy};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating a custom domain; only the provided fields are changed
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateCustomDomainRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+
+    /// Only for self-managed certificates; `ClientIpHeader::None` removes the header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<ClientIpHeader>,
+}
+
+/// Updates a custom domain.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:custom_domains` scope
+/// * `custom_domain_id` - The ID of the custom domain
+/// * `request` - The fields to change
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The custom domain ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    custom_domain_id: &str,
+    request: UpdateCustomDomainRequest,
+) -> Result<CustomDomain> {
+    // Validate custom domain ID
+    if custom_domain_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Custom domain ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and custom domain ID
+    let endpoint = domain.to_url(&format!("/api/v2/custom-domains/{custom_domain_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the custom domain
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<CustomDomain>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_custom_domain_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/custom-domains/cd_2")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"custom_client_ip_header": "x-forwarded-for"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "custom_domain_id": "cd_2",
+                    "domain": "auth.example.org",
+                    "primary": false,
+                    "status": "ready",
+                    "type": "self_managed_certs",
+                    "custom_client_ip_header": "x-forwarded-for"
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let request = UpdateCustomDomainRequest {
+            custom_client_ip_header: Some(ClientIpHeader::XForwardedFor),
+            ..Default::default()
+        };
+        let result = update_custom_domain(&domain, &token, "cd_2", request).await;
+        mock.assert_async().await;
+
+        assert_eq!(
+            result.unwrap().custom_client_ip_header,
+            Some(ClientIpHeader::XForwardedFor)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_custom_domain_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result =
+            update_custom_domain(&domain, &token, "", UpdateCustomDomainRequest::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,308 @@
+//! Custom domain verification for Auth0 Management API v2
+//!
+//! This module provides the `verify_custom_domain` function, which asks Auth0 to check the
+//! DNS records of a custom domain. It wraps the POST /api/v2/custom-domains/{id}/verify
+//! endpoint. `wait_for_custom_domain` repeats the check until the domain is ready, for
+//! tooling that creates the DNS records and then blocks until they have propagated.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     custom_domains::wait_for_custom_domain,
+//!     error::Auth0Error,
+//! };
+//! use std::{env, time::Duration};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let custom_domain = wait_for_custom_domain(
+//!         &domain,
+//!         &token,
+//!         "cd_0000000000000001",
+//!         Duration::from_secs(30),
+//!         Duration::from_secs(30 * 60),
+//!     )
+//!     .await?;
+//!     println!("{} is ready", custom_domain.domain);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/custom-domains/post-verify)
+//! for more details about the verify custom domain endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_custom_domain::{CustomDomain, CustomDomainStatus};
+use super::get_custom_domain::get_custom_domain;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Shortest delay between checks, so a zero interval does not flood the API
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Asks Auth0 to check the DNS records of a custom domain.
+///
+/// The returned domain stays in `PendingVerification` while the records are missing. Once
+/// they are found it moves to `Pending` and then to `Ready` when the certificate is issued.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:custom_domains` scope
+/// * `custom_domain_id` - The ID of the custom domain to verify
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The custom domain ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn verify_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    custom_domain_id: &str,
+) -> Result<CustomDomain> {
+    // Validate custom domain ID
+    if custom_domain_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Custom domain ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and custom domain ID
+    let endpoint = domain.to_url(&format!("/api/v2/custom-domains/{custom_domain_id}/verify"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to verify the custom domain
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<CustomDomain>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Verifies a custom domain every `interval` until it is ready.
+///
+/// While the DNS records are missing the domain is verified again; afterwards it is only
+/// fetched until the certificate is issued. Rate limited requests are retried.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API requests
+/// * `token` - A valid Management API access token with the `create:custom_domains` and
+///   `read:custom_domains` scopes
+/// * `custom_domain_id` - The ID of the custom domain to verify
+/// * `interval` - The delay between checks, at least 100ms
+/// * `timeout` - How long to wait for the domain in total
+///
+/// # Errors
+///
+/// Returns `Auth0Error::Timeout` if the domain is not ready within `timeout`, and
+/// `Auth0Error::UnexpectedResponse` if it is disabled. Other errors of the requests are
+/// returned as-is.
+pub async fn wait_for_custom_domain(
+    domain: &Domain,
+    token: &BearerToken,
+    custom_domain_id: &str,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<CustomDomain> {
+    let interval = interval.max(MIN_INTERVAL);
+    let wait = async {
+        let mut status = CustomDomainStatus::PendingVerification;
+        loop {
+            let result = if status == CustomDomainStatus::PendingVerification {
+                verify_custom_domain(domain, token, custom_domain_id).await
+            } else {
+                get_custom_domain(domain, token, custom_domain_id).await
+            };
+
+            match result {
+                Ok(custom_domain) if custom_domain.is_ready() => return Ok(custom_domain),
+                Ok(custom_domain) if custom_domain.status == CustomDomainStatus::Disabled => {
+                    return Err(Auth0Error::UnexpectedResponse {
+                        status: StatusCode::OK.as_u16(),
+                        body: format!("custom domain {custom_domain_id} is disabled"),
+                    });
+                }
+                Ok(custom_domain) => status = custom_domain.status,
+                Err(Auth0Error::TooManyRequests(_)) => {}
+                Err(e) => return Err(e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    };
+
+    tokio::time::timeout(timeout, wait).await.map_err(|_| {
+        Auth0Error::Timeout(format!(
+            "custom domain {custom_domain_id} was not ready within {timeout:?}"
+        ))
+    })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    fn custom_domain_body(status: &str) -> String {
+        format!(
+            r#"{{
+                "custom_domain_id": "cd_1",
+                "domain": "login.example.com",
+                "primary": false,
+                "status": "{status}",
+                "type": "auth0_managed_certs"
+            }}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_verify_custom_domain_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/custom-domains/cd_1/verify")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(custom_domain_body("pending"))
+            .create_async()
+            .await;
+
+        let result = verify_custom_domain(&domain, &token, "cd_1").await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().status, CustomDomainStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_custom_domain_until_ready() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _rate_limited = server
+            .mock("POST", "/api/v2/custom-domains/cd_1/verify")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let verify = server
+            .mock("POST", "/api/v2/custom-domains/cd_1/verify")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(custom_domain_body("pending"))
+            .expect(1)
+            .create_async()
+            .await;
+        let ready = server
+            .mock("GET", "/api/v2/custom-domains/cd_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(custom_domain_body("ready"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let result = wait_for_custom_domain(
+            &domain,
+            &token,
+            "cd_1",
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await;
+        verify.assert_async().await;
+        ready.assert_async().await;
+
+        assert!(result.unwrap().is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_custom_domain_timeout() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("POST", "/api/v2/custom-domains/cd_1/verify")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(custom_domain_body("pending_verification"))
+            .create_async()
+            .await;
+
+        let result = wait_for_custom_domain(
+            &domain,
+            &token,
+            "cd_1",
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        )
+        .await;
+
+        assert!(matches!(result, Err(Auth0Error::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_zero_interval_is_clamped() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/custom-domains/cd_1/verify")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(custom_domain_body("pending_verification"))
+            .expect_at_most(3)
+            .create_async()
+            .await;
+
+        let result = wait_for_custom_domain(
+            &domain,
+            &token,
+            "cd_1",
+            Duration::ZERO,
+            Duration::from_millis(250),
+        )
+        .await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Timeout(_))));
+    }
+}
@@ -40,6 +40,7 @@ pub struct ChangePasswordRequest {
     pub email: String,
 
     /// The name of the database connection configured for your application.
+    /// Valid names can be discovered with [`crate::connections::list_connections`].
     pub connection: String,
 
     /// The organization ID (optional).
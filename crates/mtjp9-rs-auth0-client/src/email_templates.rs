@@ -0,0 +1,14 @@
+//! Email template functionality for Auth0
+//!
+//! This module provides functions for managing the templates of the emails Auth0 sends,
+//! such as the password reset email, through the Auth0 Management API v2.
+
+mod get_email_template;
+mod patch_email_template;
+mod put_email_template;
+
+pub use get_email_template::{
+    get_email_template, EmailTemplate, EmailTemplateName, EmailTemplateSyntax,
+};
+pub use patch_email_template::{patch_email_template, PatchEmailTemplateRequest};
+pub use put_email_template::put_email_template;
@@ -0,0 +1,310 @@
+//! Email template retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_email_template` function for fetching one of the email
+//! templates of the tenant. It wraps the GET /api/v2/email-templates/{templateName}
+//! endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     email_templates::{EmailTemplateName, get_email_template},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     match get_email_template(&domain, &token, &EmailTemplateName::ResetEmail).await? {
+//!         Some(template) => println!("Subject: {:?}", template.subject),
+//!         None => println!("The default reset email is used"),
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/email-templates/get-email-templates-by-template-name)
+//! for more details about the get email template endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An email Auth0 sends, identified by its template name
+///
+/// Templates not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EmailTemplateName {
+    /// `verify_email`: link to verify an email address
+    VerifyEmail,
+    /// `verify_email_by_code`: code to verify an email address
+    VerifyEmailByCode,
+    /// `reset_email`: link to reset a password, sent by `change_password` and
+    /// `create_password_change_ticket`
+    ResetEmail,
+    /// `reset_email_by_code`: code to reset a password
+    ResetEmailByCode,
+    /// `welcome_email`: sent after the email address was verified
+    WelcomeEmail,
+    /// `blocked_account`: sent when an account is blocked by brute-force protection
+    BlockedAccount,
+    /// `stolen_credentials`: sent when breached password detection finds the password
+    StolenCredentials,
+    /// `enrollment_email`: invitation to enroll in MFA
+    EnrollmentEmail,
+    /// `mfa_oob_code`: MFA code sent by email
+    MfaOobCode,
+    /// `user_invitation`: invitation to join an organization
+    UserInvitation,
+    /// `async_approval`: request to approve a transaction
+    AsyncApproval,
+    /// Any other template
+    Other(String),
+}
+
+impl EmailTemplateName {
+    /// The name Auth0 uses for this template.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::VerifyEmail => "verify_email",
+            Self::VerifyEmailByCode => "verify_email_by_code",
+            Self::ResetEmail => "reset_email",
+            Self::ResetEmailByCode => "reset_email_by_code",
+            Self::WelcomeEmail => "welcome_email",
+            Self::BlockedAccount => "blocked_account",
+            Self::StolenCredentials => "stolen_credentials",
+            Self::EnrollmentEmail => "enrollment_email",
+            Self::MfaOobCode => "mfa_oob_code",
+            Self::UserInvitation => "user_invitation",
+            Self::AsyncApproval => "async_approval",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for EmailTemplateName {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "verify_email" => Self::VerifyEmail,
+            "verify_email_by_code" => Self::VerifyEmailByCode,
+            "reset_email" => Self::ResetEmail,
+            "reset_email_by_code" => Self::ResetEmailByCode,
+            "welcome_email" => Self::WelcomeEmail,
+            "blocked_account" => Self::BlockedAccount,
+            "stolen_credentials" => Self::StolenCredentials,
+            "enrollment_email" => Self::EnrollmentEmail,
+            "mfa_oob_code" => Self::MfaOobCode,
+            "user_invitation" => Self::UserInvitation,
+            "async_approval" => Self::AsyncApproval,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<EmailTemplateName> for String {
+    fn from(name: EmailTemplateName) -> Self {
+        match name {
+            EmailTemplateName::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for EmailTemplateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Template language of an email body and subject
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailTemplateSyntax {
+    Liquid,
+}
+
+/// An email template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplate {
+    pub template: EmailTemplateName,
+
+    /// The HTML body of the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The `From` address, overriding the default address of the email provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syntax: Option<EmailTemplateSyntax>,
+
+    /// Where users are redirected after following the link in the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+
+    /// How long the link in the email stays valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+
+    /// Whether the user's email address is appended to the result URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+
+    /// Whether the email is sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl EmailTemplate {
+    /// Creates an enabled Liquid template.
+    pub fn new(
+        template: EmailTemplateName,
+        from: impl Into<String>,
+        subject: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            template,
+            body: Some(body.into()),
+            from: Some(from.into()),
+            subject: Some(subject.into()),
+            syntax: Some(EmailTemplateSyntax::Liquid),
+            result_url: None,
+            url_lifetime_in_seconds: None,
+            include_email_in_redirect: None,
+            enabled: Some(true),
+        }
+    }
+}
+
+/// Fetches an email template.
+///
+/// Returns `None` if the template was never configured, in which case Auth0 sends its
+/// default email.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:email_templates` scope
+/// * `template` - The name of the template
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes) other than 404
+/// * The response cannot be deserialized
+pub async fn get_email_template(
+    domain: &Domain,
+    token: &BearerToken,
+    template: &EmailTemplateName,
+) -> Result<Option<EmailTemplate>> {
+    // Construct the API endpoint URL using the provided domain and template name
+    let endpoint = domain.to_url(&format!("/api/v2/email-templates/{template}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the email template
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<EmailTemplate>()
+            .await
+            .map(Some)
+            .map_err(Auth0Error::from),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_email_template_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/email-templates/reset_email")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "template": "reset_email",
+                    "body": "<a href=\"{{ url }}\">Reset</a>",
+                    "from": "security@example.com",
+                    "subject": "Reset your password",
+                    "syntax": "liquid",
+                    "resultUrl": "https://example.com/login",
+                    "urlLifetimeInSeconds": 3600,
+                    "enabled": true
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_email_template(&domain, &token, &EmailTemplateName::ResetEmail).await;
+        mock.assert_async().await;
+
+        let template = result.unwrap().unwrap();
+        assert_eq!(template.template, EmailTemplateName::ResetEmail);
+        assert_eq!(template.syntax, Some(EmailTemplateSyntax::Liquid));
+        assert_eq!(template.url_lifetime_in_seconds, Some(3600));
+    }
+
+    #[tokio::test]
+    async fn test_get_email_template_not_configured() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/email-templates/welcome_email")
+            .with_status(404)
+            .with_body(r#"{"message": "Template not found"}"#)
+            .create_async()
+            .await;
+
+        let result = get_email_template(&domain, &token, &EmailTemplateName::WelcomeEmail).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_none());
+    }
+}
@@ -0,0 +1,161 @@
+//! Email template updates for Auth0 Management API v2
+//!
+//! This module provides the `patch_email_template` function for changing some fields of
+//! an email template, for example to disable it. It wraps the
+//! PATCH /api/v2/email-templates/{templateName} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/email-templates/patch-email-templates-by-template-name)
+//! for more details about the patch email template endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::get_email_template::{EmailTemplate, EmailTemplateName, EmailTemplateSyntax};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating an email template
+///
+/// All fields are optional. Only fields that are provided will be updated.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchEmailTemplateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syntax: Option<EmailTemplateSyntax>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// Updates some fields of an email template.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:email_templates` scope
+/// * `template` - The name of the template
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes), such as 404 when the
+///   template was never configured
+/// * The response cannot be deserialized
+pub async fn patch_email_template(
+    domain: &Domain,
+    token: &BearerToken,
+    template: &EmailTemplateName,
+    request: PatchEmailTemplateRequest,
+) -> Result<EmailTemplate> {
+    // Construct the API endpoint URL using the provided domain and template name
+    let endpoint = domain.to_url(&format!("/api/v2/email-templates/{template}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the email template
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<EmailTemplate>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_patch_email_template_disable() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/email-templates/welcome_email")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(r#"{"enabled": false}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"template": "welcome_email", "enabled": false}"#)
+            .create_async()
+            .await;
+
+        let request = PatchEmailTemplateRequest {
+            enabled: Some(false),
+            ..Default::default()
+        };
+        let result =
+            patch_email_template(&domain, &token, &EmailTemplateName::WelcomeEmail, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().enabled, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_patch_email_template_not_found() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/email-templates/blocked_account")
+            .with_status(404)
+            .with_body(r#"{"message": "Template not found"}"#)
+            .create_async()
+            .await;
+
+        let result = patch_email_template(
+            &domain,
+            &token,
+            &EmailTemplateName::BlockedAccount,
+            PatchEmailTemplateRequest::default(),
+        )
+        .await;
+        mock.assert_async().await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Email template replacement for Auth0 Management API v2
+//!
+//! This module provides the `put_email_template` function for setting the whole content
+//! of an email template, creating it if it was never configured. It wraps the
+//! PUT /api/v2/email-templates/{templateName} endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     email_templates::{EmailTemplate, EmailTemplateName, put_email_template},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let template = EmailTemplate {
+//!         result_url: Some("https://example.com/login".to_string()),
+//!         ..EmailTemplate::new(
+//!             EmailTemplateName::ResetEmail,
+//!             "security@example.com",
+//!             "Reset your password",
+//!             "<a href=\"{{ url }}\">Reset your password</a>",
+//!         )
+//!     };
+//!
+//!     put_email_template(&domain, &token, template).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/email-templates/put-email-templates-by-template-name)
+//! for more details about the put email template endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::get_email_template::EmailTemplate;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sets the whole content of an email template.
+///
+/// Fields left unset are reset to their defaults.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:email_templates` scope
+/// * `template` - The template, named by its `template` field
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn put_email_template(
+    domain: &Domain,
+    token: &BearerToken,
+    template: EmailTemplate,
+) -> Result<EmailTemplate> {
+    // Construct the API endpoint URL using the provided domain and template name
+    let endpoint = domain.to_url(&format!("/api/v2/email-templates/{}", template.template));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to replace the email template
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&template)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => response
+            .json::<EmailTemplate>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::email_templates::EmailTemplateName;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_put_email_template_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/email-templates/user_invitation")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{
+                    "template": "user_invitation",
+                    "body": "<p>Join {{ organization.display_name }}</p>",
+                    "from": "team@example.com",
+                    "subject": "You are invited",
+                    "syntax": "liquid",
+                    "enabled": true
+                }"#
+                .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"template": "user_invitation", "body": "<p>Join {{ organization.display_name }}</p>", "from": "team@example.com", "subject": "You are invited", "syntax": "liquid", "enabled": true}"#,
+            )
+            .create_async()
+            .await;
+
+        let template = EmailTemplate::new(
+            EmailTemplateName::UserInvitation,
+            "team@example.com",
+            "You are invited",
+            "<p>Join {{ organization.display_name }}</p>",
+        );
+        let result = put_email_template(&domain, &token, template).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().enabled, Some(true));
+    }
+}
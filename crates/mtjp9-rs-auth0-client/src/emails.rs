@@ -0,0 +1,18 @@
+//! Email provider functionality for Auth0
+//!
+//! This module provides functions for managing the provider Auth0 sends emails through,
+//! via the Auth0 Management API v2. The content of the emails is managed with
+//! [`email_templates`](crate::email_templates).
+
+mod create_email_provider;
+mod delete_email_provider;
+mod get_email_provider;
+mod update_email_provider;
+
+pub use create_email_provider::{
+    create_email_provider, CreateEmailProviderRequest, EmailProvider, EmailProviderCredentials,
+    EmailProviderName,
+};
+pub use delete_email_provider::delete_email_provider;
+pub use get_email_provider::get_email_provider;
+pub use update_email_provider::{update_email_provider, UpdateEmailProviderRequest};
@@ -0,0 +1,429 @@
+//! Email provider configuration for Auth0 Management API v2
+//!
+//! This module provides the `create_email_provider` function for configuring the provider
+//! Auth0 sends emails through, such as verification and password reset emails. It wraps
+//! the POST /api/v2/emails/provider endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     emails::{CreateEmailProviderRequest, EmailProviderCredentials, EmailProviderName, create_email_provider},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateEmailProviderRequest {
+//!         default_from_address: Some("no-reply@example.com".to_string()),
+//!         ..CreateEmailProviderRequest::new(
+//!             EmailProviderName::Smtp,
+//!             EmailProviderCredentials::smtp("smtp.example.com", 587, "auth0", "s3cr3t"),
+//!         )
+//!     };
+//!
+//!     let provider = create_email_provider(&domain, &token, request).await?;
+//!     println!("Emails are sent through {}", provider.name);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/emails/post-provider)
+//! for more details about the create email provider endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Service Auth0 sends emails through
+///
+/// Providers not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EmailProviderName {
+    Mailgun,
+    Mandrill,
+    Sendgrid,
+    /// Amazon SES
+    Ses,
+    Sparkpost,
+    Smtp,
+    /// Azure Communication Services
+    AzureCs,
+    /// Microsoft 365
+    Ms365,
+    /// Emails sent by a `custom-email-provider` Action
+    Custom,
+    /// Any other provider
+    Other(String),
+}
+
+impl EmailProviderName {
+    /// The name Auth0 uses for this provider.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mailgun => "mailgun",
+            Self::Mandrill => "mandrill",
+            Self::Sendgrid => "sendgrid",
+            Self::Ses => "ses",
+            Self::Sparkpost => "sparkpost",
+            Self::Smtp => "smtp",
+            Self::AzureCs => "azure_cs",
+            Self::Ms365 => "ms365",
+            Self::Custom => "custom",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for EmailProviderName {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "mailgun" => Self::Mailgun,
+            "mandrill" => Self::Mandrill,
+            "sendgrid" => Self::Sendgrid,
+            "ses" => Self::Ses,
+            "sparkpost" => Self::Sparkpost,
+            "smtp" => Self::Smtp,
+            "azure_cs" => Self::AzureCs,
+            "ms365" => Self::Ms365,
+            "custom" => Self::Custom,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<EmailProviderName> for String {
+    fn from(name: EmailProviderName) -> Self {
+        match name {
+            EmailProviderName::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for EmailProviderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Credentials of an email provider
+///
+/// Each provider uses a subset of the fields. Secrets are never returned by the API, so
+/// they are `None` in fetched providers, and `Debug` output redacts them.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmailProviderCredentials {
+    /// API key of Mailgun, Mandrill, SendGrid or SparkPost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+
+    /// Sending domain of Mailgun
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Region of Mailgun (`eu`), SES or SparkPost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    #[serde(rename = "accessKeyId", skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+
+    #[serde(rename = "secretAccessKey", skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_host: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_port: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_pass: Option<String>,
+
+    /// Connection string of Azure Communication Services
+    #[serde(rename = "connectionString", skip_serializing_if = "Option::is_none")]
+    pub connection_string: Option<String>,
+
+    /// Microsoft 365 tenant ID
+    #[serde(rename = "tenantId", skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+
+    /// Microsoft 365 application ID
+    #[serde(rename = "clientId", skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Microsoft 365 application secret
+    #[serde(rename = "clientSecret", skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+impl fmt::Debug for EmailProviderCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = |secret: &Option<String>| secret.as_ref().map(|_| "[REDACTED]");
+        f.debug_struct("EmailProviderCredentials")
+            .field("api_key", &redacted(&self.api_key))
+            .field("domain", &self.domain)
+            .field("region", &self.region)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &redacted(&self.secret_access_key))
+            .field("smtp_host", &self.smtp_host)
+            .field("smtp_port", &self.smtp_port)
+            .field("smtp_user", &self.smtp_user)
+            .field("smtp_pass", &redacted(&self.smtp_pass))
+            .field("connection_string", &redacted(&self.connection_string))
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &redacted(&self.client_secret))
+            .finish()
+    }
+}
+
+impl EmailProviderCredentials {
+    /// Credentials of an SMTP server.
+    pub fn smtp(
+        host: impl Into<String>,
+        port: u16,
+        user: impl Into<String>,
+        pass: impl Into<String>,
+    ) -> Self {
+        Self {
+            smtp_host: Some(host.into()),
+            smtp_port: Some(port),
+            smtp_user: Some(user.into()),
+            smtp_pass: Some(pass.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Credentials of Amazon SES.
+    pub fn ses(
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+        region: impl Into<String>,
+    ) -> Self {
+        Self {
+            access_key_id: Some(access_key_id.into()),
+            secret_access_key: Some(secret_access_key.into()),
+            region: Some(region.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Credentials of a provider authenticating with an API key, such as SendGrid.
+    pub fn api_key(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: Some(api_key.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Request body for configuring the email provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEmailProviderRequest {
+    pub name: EmailProviderName,
+
+    /// Whether emails are sent through the provider (defaults to true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// The `From` address of emails whose template does not set one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+
+    pub credentials: EmailProviderCredentials,
+
+    /// Provider specific settings, such as SES message tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+impl CreateEmailProviderRequest {
+    /// Creates a request for the provider with its credentials.
+    pub fn new(name: EmailProviderName, credentials: EmailProviderCredentials) -> Self {
+        Self {
+            name,
+            enabled: None,
+            default_from_address: None,
+            credentials,
+            settings: None,
+        }
+    }
+}
+
+/// The email provider of a tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailProvider {
+    pub name: EmailProviderName,
+
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+
+    /// The credentials, without their secrets
+    #[serde(default)]
+    pub credentials: EmailProviderCredentials,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+/// Configures the email provider of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:email_provider` scope
+/// * `request` - The provider and its credentials
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes), such as 409 Conflict
+///   when a provider is already configured
+/// * The response cannot be deserialized
+pub async fn create_email_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateEmailProviderRequest,
+) -> Result<EmailProvider> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/emails/provider");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to configure the email provider
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<EmailProvider>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_create_email_provider_ses() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/emails/provider")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"name": "ses", "credentials": {"accessKeyId": "AKIA", "secretAccessKey": "secret", "region": "eu-west-1"}}"#
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"name": "ses", "enabled": true, "credentials": {"accessKeyId": "AKIA", "region": "eu-west-1"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let request = CreateEmailProviderRequest::new(
+            EmailProviderName::Ses,
+            EmailProviderCredentials::ses("AKIA", "secret", "eu-west-1"),
+        );
+        let result = create_email_provider(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let provider = result.unwrap();
+        assert_eq!(provider.name, EmailProviderName::Ses);
+        assert!(provider.enabled);
+        assert_eq!(provider.credentials.secret_access_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_create_email_provider_conflict() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/emails/provider")
+            .with_status(409)
+            .with_body(r#"{"message": "An email provider is already configured"}"#)
+            .create_async()
+            .await;
+
+        let request = CreateEmailProviderRequest::new(
+            EmailProviderName::Sendgrid,
+            EmailProviderCredentials::api_key("SG.key"),
+        );
+        let result = create_email_provider(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Conflict { .. })));
+    }
+
+    #[test]
+    fn test_email_provider_name_round_trip() {
+        let name: EmailProviderName = serde_json::from_str(r#""azure_cs""#).unwrap();
+        assert_eq!(name, EmailProviderName::AzureCs);
+
+        let name: EmailProviderName = serde_json::from_str(r#""postmark""#).unwrap();
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""postmark""#);
+    }
+
+    #[test]
+    fn test_credentials_debug_redacts_secrets() {
+        let smtp = EmailProviderCredentials::smtp("smtp.example.com", 587, "mailer", "smtp-secret");
+        let ses = EmailProviderCredentials::ses("AKIA123", "aws-secret", "eu-west-1");
+        let request = CreateEmailProviderRequest::new(
+            EmailProviderName::Sendgrid,
+            EmailProviderCredentials::api_key("SG.secret"),
+        );
+
+        let output = format!("{smtp:?} {ses:?} {request:?}");
+        assert!(output.contains("smtp.example.com") && output.contains("AKIA123"));
+        for secret in ["smtp-secret", "aws-secret", "SG.secret"] {
+            assert!(!output.contains(secret));
+        }
+    }
+}
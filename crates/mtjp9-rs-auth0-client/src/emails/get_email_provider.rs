@@ -0,0 +1,120 @@
+//! Email provider retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_email_provider` function for fetching the email provider
+//! of the tenant. It wraps the GET /api/v2/emails/provider endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/emails/get-provider)
+//! for more details about the get email provider endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_email_provider::EmailProvider;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches the email provider of the tenant.
+///
+/// Returns `None` if no email provider is configured, in which case Auth0 sends emails
+/// through its own test provider.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:email_provider` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes) other than 404
+/// * The response cannot be deserialized
+pub async fn get_email_provider(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<Option<EmailProvider>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/emails/provider");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the email provider
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<EmailProvider>()
+            .await
+            .map(Some)
+            .map_err(Auth0Error::from),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emails::EmailProviderName;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_email_provider_smtp() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/emails/provider")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"name": "smtp", "enabled": true, "default_from_address": "no-reply@example.com", "credentials": {"smtp_host": "smtp.example.com", "smtp_port": 587, "smtp_user": "auth0"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_email_provider(&domain, &token).await;
+        mock.assert_async().await;
+
+        let provider = result.unwrap().unwrap();
+        assert_eq!(provider.name, EmailProviderName::Smtp);
+        assert_eq!(provider.credentials.smtp_port, Some(587));
+        assert_eq!(provider.credentials.smtp_pass, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_email_provider_not_configured() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/emails/provider")
+            .with_status(404)
+            .with_body(r#"{"message": "Email provider is not configured"}"#)
+            .create_async()
+            .await;
+
+        let result = get_email_provider(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_none());
+    }
+}
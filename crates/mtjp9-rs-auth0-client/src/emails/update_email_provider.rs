@@ -0,0 +1,127 @@
+//! Email provider updates for Auth0 Management API v2
+//!
+//! This module provides the `update_email_provider` function for changing the email
+//! provider of the tenant, for example to rotate its credentials. It wraps the
+//! PATCH /api/v2/emails/provider endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/emails/patch-provider)
+//! for more details about the update email provider endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+use super::create_email_provider::{EmailProvider, EmailProviderCredentials, EmailProviderName};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating the email provider
+///
+/// All fields are optional. Only fields that are provided will be updated. Credentials
+/// must be complete when the provider is changed.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdateEmailProviderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<EmailProviderName>,
+
+    /// Set to `false` to fall back to the Auth0 test provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<EmailProviderCredentials>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+/// Updates the email provider of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:email_provider` scope
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_email_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UpdateEmailProviderRequest,
+) -> Result<EmailProvider> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/emails/provider");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the email provider
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<EmailProvider>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_email_provider_rotate_key() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/emails/provider")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"credentials": {"api_key": "SG.new"}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"name": "sendgrid", "enabled": true, "credentials": {}}"#)
+            .create_async()
+            .await;
+
+        let request = UpdateEmailProviderRequest {
+            credentials: Some(EmailProviderCredentials::api_key("SG.new")),
+            ..Default::default()
+        };
+        let result = update_email_provider(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().name, EmailProviderName::Sendgrid);
+    }
+}
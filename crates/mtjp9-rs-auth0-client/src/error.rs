@@ -26,6 +26,18 @@ pub enum Auth0Error {
     #[error("rate limited: {0}")]
     TooManyRequests(String),
 
+    /// An operation did not complete within its deadline.
+    #[error("timed out: {0}")]
+    Timeout(String),
+
+    /// An operation was cancelled by the caller.
+    #[error("cancelled: {0}")]
+    Cancelled(String),
+
+    /// A successful response body could not be decoded.
+    #[error("decode error: {0}")]
+    Decode(String),
+
     /// Any other non‑success HTTP status.
     #[error("unexpected status {status}: {body}")]
     UnexpectedResponse { status: u16, body: String },
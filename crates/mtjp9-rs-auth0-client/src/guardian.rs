@@ -0,0 +1,23 @@
+//! Guardian MFA functionality for Auth0
+//!
+//! This module provides functions for configuring multi-factor authentication through the
+//! Auth0 Management API v2: factors and their providers, MFA policies, SMS templates and
+//! enrollment tickets.
+
+mod enrollment_ticket;
+mod factors;
+mod policies;
+mod providers;
+mod sms_templates;
+
+pub use enrollment_ticket::{
+    create_enrollment_ticket, CreateEnrollmentTicketRequest, EnrollmentTicket,
+};
+pub use factors::{list_guardian_factors, set_guardian_factor, GuardianFactor, GuardianFactorName};
+pub use policies::{get_mfa_policies, set_mfa_policies, MfaPolicy};
+pub use providers::{
+    get_duo_settings, get_sns_provider, get_twilio_provider, put_duo_settings, put_sns_provider,
+    put_twilio_provider, set_phone_provider, DuoSettings, PhoneProvider, SnsProvider,
+    TwilioProvider,
+};
+pub use sms_templates::{get_sms_templates, put_sms_templates, SmsTemplates};
@@ -0,0 +1,202 @@
+//! Guardian enrollment tickets for Auth0 Management API v2
+//!
+//! This module provides the `create_enrollment_ticket` function for creating a link that
+//! lets a user enroll in MFA, optionally emailing it to them. It wraps the
+//! POST /api/v2/guardian/enrollments/ticket endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     guardian::{CreateEnrollmentTicketRequest, create_enrollment_ticket},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateEnrollmentTicketRequest {
+//!         send_mail: Some(true),
+//!         email_locale: Some("ja".to_string()),
+//!         ..CreateEnrollmentTicketRequest::new("auth0|123")
+//!     };
+//!
+//!     let ticket = create_enrollment_ticket(&domain, &token, request).await?;
+//!     println!("Enrollment link: {}", ticket.ticket_url);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/guardian/post-ticket)
+//! for more details about the create enrollment ticket endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::factors::GuardianFactorName;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for creating an enrollment ticket
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateEnrollmentTicketRequest {
+    /// The user who enrolls
+    pub user_id: String,
+
+    /// The address the ticket is emailed to, instead of the user's email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Whether Auth0 emails the ticket to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_mail: Option<bool>,
+
+    /// The language of the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_locale: Option<String>,
+
+    /// The factor the user enrolls in, instead of choosing one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factor: Option<GuardianFactorName>,
+
+    /// Whether the user may enroll even if they already have factors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_multiple_enrollments: Option<bool>,
+}
+
+impl CreateEnrollmentTicketRequest {
+    /// Creates a request for the user.
+    pub fn new(user_id: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// An enrollment ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrollmentTicket {
+    pub ticket_id: String,
+
+    /// The link the user follows to enroll
+    pub ticket_url: String,
+}
+
+/// Creates an MFA enrollment ticket for a user.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:guardian_enrollment_tickets` scope
+/// * `request` - The user and how the ticket is delivered
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The user ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn create_enrollment_ticket(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateEnrollmentTicketRequest,
+) -> Result<EnrollmentTicket> {
+    // Validate user ID
+    if request.user_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "User ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/enrollments/ticket");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the ticket
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => response
+            .json::<EnrollmentTicket>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_create_enrollment_ticket_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/guardian/enrollments/ticket")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"user_id": "auth0|123", "send_mail": false, "factor": "webauthn-roaming"}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"ticket_id": "tkt_1", "ticket_url": "https://example.auth0.com/guardian/enroll?ticket=tkt_1"}"#,
+            )
+            .create_async()
+            .await;
+
+        let request = CreateEnrollmentTicketRequest {
+            send_mail: Some(false),
+            factor: Some(GuardianFactorName::WebauthnRoaming),
+            ..CreateEnrollmentTicketRequest::new("auth0|123")
+        };
+        let result = create_enrollment_ticket(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().ticket_id, "tkt_1");
+    }
+
+    #[tokio::test]
+    async fn test_create_enrollment_ticket_empty_user_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result =
+            create_enrollment_ticket(&domain, &token, CreateEnrollmentTicketRequest::default())
+                .await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
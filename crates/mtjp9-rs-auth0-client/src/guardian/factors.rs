@@ -0,0 +1,288 @@
+//! Guardian MFA factor management for Auth0 Management API v2
+//!
+//! This module provides functions for listing the multi-factor authentication factors of
+//! the tenant and enabling or disabling them. It wraps the /api/v2/guardian/factors
+//! endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     guardian::{GuardianFactorName, list_guardian_factors, set_guardian_factor},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Only allow one-time passwords and security keys
+//!     for factor in list_guardian_factors(&domain, &token).await? {
+//!         let enabled = matches!(factor.name, GuardianFactorName::Otp | GuardianFactorName::WebauthnRoaming);
+//!         if factor.enabled != enabled {
+//!             set_guardian_factor(&domain, &token, &factor.name, enabled).await?;
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/guardian/get-factors)
+//! for more details about the factors endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A multi-factor authentication factor
+///
+/// Factors not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum GuardianFactorName {
+    /// Codes sent by text message
+    Sms,
+    /// Codes sent by text message or voice call
+    Phone,
+    /// Codes sent by email
+    Email,
+    /// One-time passwords of an authenticator app
+    Otp,
+    /// Push notifications to the Auth0 Guardian app
+    PushNotification,
+    /// Duo Security
+    Duo,
+    /// Security keys
+    WebauthnRoaming,
+    /// Device biometrics
+    WebauthnPlatform,
+    RecoveryCode,
+    /// Any other factor
+    Other(String),
+}
+
+impl GuardianFactorName {
+    /// The name Auth0 uses for this factor.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sms => "sms",
+            Self::Phone => "phone",
+            Self::Email => "email",
+            Self::Otp => "otp",
+            Self::PushNotification => "push-notification",
+            Self::Duo => "duo",
+            Self::WebauthnRoaming => "webauthn-roaming",
+            Self::WebauthnPlatform => "webauthn-platform",
+            Self::RecoveryCode => "recovery-code",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for GuardianFactorName {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "sms" => Self::Sms,
+            "phone" => Self::Phone,
+            "email" => Self::Email,
+            "otp" => Self::Otp,
+            "push-notification" => Self::PushNotification,
+            "duo" => Self::Duo,
+            "webauthn-roaming" => Self::WebauthnRoaming,
+            "webauthn-platform" => Self::WebauthnPlatform,
+            "recovery-code" => Self::RecoveryCode,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<GuardianFactorName> for String {
+    fn from(name: GuardianFactorName) -> Self {
+        match name {
+            GuardianFactorName::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for GuardianFactorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A factor and whether users may enroll in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianFactor {
+    pub name: GuardianFactorName,
+
+    pub enabled: bool,
+
+    /// Whether the trial of a paid factor has expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_expired: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct FactorStatus {
+    enabled: bool,
+}
+
+/// Lists the MFA factors of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:guardian_factors` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn list_guardian_factors(
+    domain: &Domain,
+    token: &BearerToken,
+) -> Result<Vec<GuardianFactor>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/factors");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the factors
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<GuardianFactor>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Enables or disables an MFA factor.
+///
+/// Returns whether the factor is enabled after the update.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:guardian_factors` scope
+/// * `factor` - The factor
+/// * `enabled` - Whether users may enroll in the factor
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn set_guardian_factor(
+    domain: &Domain,
+    token: &BearerToken,
+    factor: &GuardianFactorName,
+    enabled: bool,
+) -> Result<bool> {
+    // Construct the API endpoint URL using the provided domain and factor name
+    let endpoint = domain.to_url(&format!("/api/v2/guardian/factors/{factor}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to enable or disable the factor
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&json!({ "enabled": enabled }))
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<FactorStatus>()
+            .await
+            .map(|status| status.enabled)
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_guardian_factors_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/guardian/factors")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"name": "sms", "enabled": false, "trial_expired": false},
+                    {"name": "push-notification", "enabled": true, "trial_expired": false},
+                    {"name": "webauthn-roaming", "enabled": true}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_guardian_factors(&domain, &token).await;
+        mock.assert_async().await;
+
+        let factors = result.unwrap();
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors[1].name, GuardianFactorName::PushNotification);
+        assert!(factors[2].enabled);
+    }
+
+    #[tokio::test]
+    async fn test_set_guardian_factor_enable() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/guardian/factors/otp")
+            .match_body(Matcher::JsonString(r#"{"enabled": true}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"enabled": true}"#)
+            .create_async()
+            .await;
+
+        let result = set_guardian_factor(&domain, &token, &GuardianFactorName::Otp, true).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap());
+    }
+}
@@ -0,0 +1,197 @@
+//! Guardian MFA policy management for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and setting when users are prompted for
+//! MFA. It wraps the /api/v2/guardian/policies endpoints. Without a policy, MFA is only
+//! required where an Action or the application asks for it.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/guardian/put-policies)
+//! for more details about the policies endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// When users are prompted for MFA
+///
+/// Policies not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum MfaPolicy {
+    /// `all-applications`: on every login
+    AllApplications,
+    /// `confidence-score`: when the login looks risky (Adaptive MFA)
+    ConfidenceScore,
+    /// Any other policy
+    Other(String),
+}
+
+impl MfaPolicy {
+    /// The name Auth0 uses for this policy.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AllApplications => "all-applications",
+            Self::ConfidenceScore => "confidence-score",
+            Self::Other(policy) => policy,
+        }
+    }
+}
+
+impl From<String> for MfaPolicy {
+    fn from(policy: String) -> Self {
+        match policy.as_str() {
+            "all-applications" => Self::AllApplications,
+            "confidence-score" => Self::ConfidenceScore,
+            _ => Self::Other(policy),
+        }
+    }
+}
+
+impl From<MfaPolicy> for String {
+    fn from(policy: MfaPolicy) -> Self {
+        match policy {
+            MfaPolicy::Other(policy) => policy,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MfaPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Fetches the MFA policies of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:mfa_policies` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_mfa_policies(domain: &Domain, token: &BearerToken) -> Result<Vec<MfaPolicy>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/policies");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the policies
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<MfaPolicy>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Sets the MFA policies of the tenant. An empty list removes all policies.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:mfa_policies` scope
+/// * `policies` - The policies, at most one
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn set_mfa_policies(
+    domain: &Domain,
+    token: &BearerToken,
+    policies: &[MfaPolicy],
+) -> Result<Vec<MfaPolicy>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/policies");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the policies
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(policies)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<MfaPolicy>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_set_mfa_policies_all_applications() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/guardian/policies")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(r#"["all-applications"]"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"["all-applications"]"#)
+            .create_async()
+            .await;
+
+        let result = set_mfa_policies(&domain, &token, &[MfaPolicy::AllApplications]).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), vec![MfaPolicy::AllApplications]);
+    }
+
+    #[tokio::test]
+    async fn test_get_mfa_policies_none() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/guardian/policies")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let result = get_mfa_policies(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_empty());
+    }
+}
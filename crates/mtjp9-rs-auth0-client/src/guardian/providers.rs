@@ -0,0 +1,483 @@
+//! Guardian factor provider configuration for Auth0 Management API v2
+//!
+//! This module provides functions for configuring the services that deliver MFA
+//! challenges: Twilio for text messages and voice calls, Amazon SNS for push
+//! notifications and Duo Security. It wraps the /api/v2/guardian/factors/{factor}/providers
+//! and /api/v2/guardian/factors/duo/settings endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     guardian::{GuardianFactorName, PhoneProvider, TwilioProvider, put_twilio_provider, set_phone_provider},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let twilio = TwilioProvider {
+//!         sid: Some(env::var("TWILIO_SID").unwrap_or_default()),
+//!         auth_token: Some(env::var("TWILIO_AUTH_TOKEN").unwrap_or_default()),
+//!         messaging_service_sid: Some("MG0123456789abcdef".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     put_twilio_provider(&domain, &token, &GuardianFactorName::Sms, &twilio).await?;
+//!     set_phone_provider(&domain, &token, &GuardianFactorName::Sms, PhoneProvider::Twilio).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/guardian/put-twilio)
+//! for more details about the factor provider endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+use std::time::Duration;
+
+use super::factors::GuardianFactorName;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Service delivering the codes of the `sms` and `phone` factors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PhoneProvider {
+    /// Auth0's own provider, for testing only
+    Auth0,
+    Twilio,
+    /// A `send-phone-message` Action
+    PhoneMessageHook,
+}
+
+/// Twilio configuration of the `sms` or `phone` factor
+///
+/// Set either `from` or `messaging_service_sid`. The auth token is never returned by the
+/// API, and `Debug` output redacts it.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TwilioProvider {
+    /// The phone number messages are sent from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// The SID of the Twilio Messaging Service messages are sent through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messaging_service_sid: Option<String>,
+
+    /// The Twilio account SID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+
+    /// The Twilio auth token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_token: Option<String>,
+}
+
+impl fmt::Debug for TwilioProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TwilioProvider")
+            .field("from", &self.from)
+            .field("messaging_service_sid", &self.messaging_service_sid)
+            .field("sid", &self.sid)
+            .field("auth_token", &redacted(&self.auth_token))
+            .finish()
+    }
+}
+
+/// Amazon SNS configuration of the `push-notification` factor
+///
+/// `Debug` output redacts the secret access key.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnsProvider {
+    /// The access key ID of the AWS IAM user publishing to SNS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_access_key_id: Option<String>,
+
+    /// The secret access key of the AWS IAM user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_secret_access_key: Option<String>,
+
+    /// The AWS region of the SNS platform applications, such as `us-west-2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+
+    /// Platform application ARN for iOS devices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sns_apns_platform_application_arn: Option<String>,
+
+    /// Platform application ARN for Android devices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sns_gcm_platform_application_arn: Option<String>,
+}
+
+impl fmt::Debug for SnsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnsProvider")
+            .field("aws_access_key_id", &self.aws_access_key_id)
+            .field(
+                "aws_secret_access_key",
+                &redacted(&self.aws_secret_access_key),
+            )
+            .field("aws_region", &self.aws_region)
+            .field(
+                "sns_apns_platform_application_arn",
+                &self.sns_apns_platform_application_arn,
+            )
+            .field(
+                "sns_gcm_platform_application_arn",
+                &self.sns_gcm_platform_application_arn,
+            )
+            .finish()
+    }
+}
+
+/// Duo Security configuration of the `duo` factor
+///
+/// `Debug` output redacts the secret key.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DuoSettings {
+    /// The integration key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ikey: Option<String>,
+
+    /// The secret key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skey: Option<String>,
+
+    /// The API hostname
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+}
+
+impl fmt::Debug for DuoSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DuoSettings")
+            .field("ikey", &self.ikey)
+            .field("skey", &redacted(&self.skey))
+            .field("host", &self.host)
+            .finish()
+    }
+}
+
+fn redacted(secret: &Option<String>) -> Option<&'static str> {
+    secret.as_ref().map(|_| "[REDACTED]")
+}
+
+#[derive(Deserialize)]
+struct SelectedProvider {
+    provider: PhoneProvider,
+}
+
+/// Fetches the Twilio configuration of the `sms` or `phone` factor.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the factor is not `sms` or `phone`, the request fails or
+/// the API returns an error response.
+pub async fn get_twilio_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    factor: &GuardianFactorName,
+) -> Result<TwilioProvider> {
+    let path = format!("{}/providers/twilio", phone_factor_path(factor)?);
+    get_settings(domain, token, &path).await
+}
+
+/// Sets the Twilio configuration of the `sms` or `phone` factor.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the factor is not `sms` or `phone`, the request fails or
+/// the API returns an error response.
+pub async fn put_twilio_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    factor: &GuardianFactorName,
+    provider: &TwilioProvider,
+) -> Result<TwilioProvider> {
+    let path = format!("{}/providers/twilio", phone_factor_path(factor)?);
+    put_settings(domain, token, &path, provider).await
+}
+
+/// Selects the service delivering the codes of the `sms` or `phone` factor.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the factor is not `sms` or `phone`, the request fails or
+/// the API returns an error response.
+pub async fn set_phone_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    factor: &GuardianFactorName,
+    provider: PhoneProvider,
+) -> Result<PhoneProvider> {
+    let path = format!("{}/selected-provider", phone_factor_path(factor)?);
+    let selected: SelectedProvider =
+        put_settings(domain, token, &path, &json!({ "provider": provider })).await?;
+    Ok(selected.provider)
+}
+
+/// Fetches the Amazon SNS configuration of push notifications.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_sns_provider(domain: &Domain, token: &BearerToken) -> Result<SnsProvider> {
+    get_settings(
+        domain,
+        token,
+        "/api/v2/guardian/factors/push-notification/providers/sns",
+    )
+    .await
+}
+
+/// Sets the Amazon SNS configuration of push notifications.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn put_sns_provider(
+    domain: &Domain,
+    token: &BearerToken,
+    provider: &SnsProvider,
+) -> Result<SnsProvider> {
+    put_settings(
+        domain,
+        token,
+        "/api/v2/guardian/factors/push-notification/providers/sns",
+        provider,
+    )
+    .await
+}
+
+/// Fetches the Duo Security configuration.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_duo_settings(domain: &Domain, token: &BearerToken) -> Result<DuoSettings> {
+    get_settings(domain, token, "/api/v2/guardian/factors/duo/settings").await
+}
+
+/// Sets the Duo Security configuration.
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn put_duo_settings(
+    domain: &Domain,
+    token: &BearerToken,
+    settings: &DuoSettings,
+) -> Result<DuoSettings> {
+    put_settings(
+        domain,
+        token,
+        "/api/v2/guardian/factors/duo/settings",
+        settings,
+    )
+    .await
+}
+
+fn phone_factor_path(factor: &GuardianFactorName) -> Result<String> {
+    match factor {
+        GuardianFactorName::Sms | GuardianFactorName::Phone => {
+            Ok(format!("/api/v2/guardian/factors/{factor}"))
+        }
+        _ => Err(Auth0Error::InvalidRequest(format!(
+            "Factor {factor} has no phone provider"
+        ))),
+    }
+}
+
+async fn get_settings<T: DeserializeOwned>(
+    domain: &Domain,
+    token: &BearerToken,
+    path: &str,
+) -> Result<T> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url(path);
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the provider configuration
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<T>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+async fn put_settings<B: Serialize, T: DeserializeOwned>(
+    domain: &Domain,
+    token: &BearerToken,
+    path: &str,
+    body: &B,
+) -> Result<T> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url(path);
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the provider configuration
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(body)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<T>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_put_twilio_provider_phone() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/guardian/factors/phone/providers/twilio")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"from": "+15555550100", "sid": "AC123", "auth_token": "secret"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"from": "+15555550100", "sid": "AC123"}"#)
+            .create_async()
+            .await;
+
+        let provider = TwilioProvider {
+            from: Some("+15555550100".to_string()),
+            sid: Some("AC123".to_string()),
+            auth_token: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let result =
+            put_twilio_provider(&domain, &token, &GuardianFactorName::Phone, &provider).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().auth_token, None);
+    }
+
+    #[tokio::test]
+    async fn test_set_phone_provider_twilio() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/guardian/factors/sms/selected-provider")
+            .match_body(Matcher::JsonString(r#"{"provider": "twilio"}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"provider": "twilio"}"#)
+            .create_async()
+            .await;
+
+        let result = set_phone_provider(
+            &domain,
+            &token,
+            &GuardianFactorName::Sms,
+            PhoneProvider::Twilio,
+        )
+        .await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), PhoneProvider::Twilio);
+    }
+
+    #[tokio::test]
+    async fn test_get_duo_settings_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/guardian/factors/duo/settings")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"ikey": "DIXXXXXXXX", "host": "api-123.duosecurity.com"}"#)
+            .create_async()
+            .await;
+
+        let result = get_duo_settings(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert_eq!(
+            result.unwrap().host.as_deref(),
+            Some("api-123.duosecurity.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_twilio_provider_invalid_factor() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_twilio_provider(&domain, &token, &GuardianFactorName::Otp).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_provider_debug_redacts_secrets() {
+        let twilio = TwilioProvider {
+            sid: Some("AC123".to_string()),
+            auth_token: Some("twilio-secret".to_string()),
+            ..Default::default()
+        };
+        let sns = SnsProvider {
+            aws_access_key_id: Some("AKIA123".to_string()),
+            aws_secret_access_key: Some("aws-secret".to_string()),
+            ..Default::default()
+        };
+        let duo = DuoSettings {
+            ikey: Some("DI123".to_string()),
+            skey: Some("duo-secret".to_string()),
+            host: None,
+        };
+
+        let output = format!("{twilio:?} {sns:?} {duo:?}");
+        assert!(output.contains("AC123") && output.contains("AKIA123"));
+        for secret in ["twilio-secret", "aws-secret", "duo-secret"] {
+            assert!(!output.contains(secret));
+        }
+    }
+}
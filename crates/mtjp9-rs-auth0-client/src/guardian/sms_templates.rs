@@ -0,0 +1,142 @@
+//! Guardian SMS template management for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and setting the text messages sent by the
+//! `sms` factor. It wraps the /api/v2/guardian/factors/sms/templates endpoints.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/guardian/put-factor-sms-templates)
+//! for more details about the SMS templates endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Text messages of the `sms` factor
+///
+/// Both are Liquid templates; `{{code}}` is replaced with the one-time code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmsTemplates {
+    /// Sent when a user enrolls a phone number
+    pub enrollment_message: String,
+
+    /// Sent when a user logs in
+    pub verification_message: String,
+}
+
+/// Fetches the text messages of the `sms` factor.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:guardian_factors` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_sms_templates(domain: &Domain, token: &BearerToken) -> Result<SmsTemplates> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/factors/sms/templates");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the templates
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<SmsTemplates>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Sets the text messages of the `sms` factor.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:guardian_factors` scope
+/// * `templates` - The enrollment and verification messages
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn put_sms_templates(
+    domain: &Domain,
+    token: &BearerToken,
+    templates: &SmsTemplates,
+) -> Result<SmsTemplates> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/guardian/factors/sms/templates");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the templates
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(templates)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<SmsTemplates>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_put_sms_templates_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let body = r#"{"enrollment_message": "{{code}} is your Acme enrollment code", "verification_message": "{{code}} is your Acme verification code"}"#;
+        let mock = server
+            .mock("PUT", "/api/v2/guardian/factors/sms/templates")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(body.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let templates = SmsTemplates {
+            enrollment_message: "{{code}} is your Acme enrollment code".to_string(),
+            verification_message: "{{code}} is your Acme verification code".to_string(),
+        };
+        let result = put_sms_templates(&domain, &token, &templates).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), templates);
+    }
+}
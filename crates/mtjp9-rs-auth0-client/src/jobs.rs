@@ -0,0 +1,27 @@
+//! Job management functionality for Auth0
+//!
+//! This module provides functions for running and monitoring long running jobs, such as
+//! bulk user imports and exports, through the Auth0 Management API v2.
+
+mod export_download;
+mod get_job;
+mod get_job_errors;
+mod import_user;
+mod job_handle;
+mod post_users_exports;
+mod post_users_imports;
+mod post_verification_email;
+
+pub use export_download::{stream_export_lines, stream_exported_users, ExportedUser};
+pub use get_job::{get_job, poll_job, Job, JobStatus, JobSummary};
+pub use get_job_errors::{get_job_errors, JobError, JobErrorDetail};
+pub use import_user::{
+    CustomPasswordHash, HashEncoding, HashKey, HashPassword, HashSalt, HashValue, HmacDigest,
+    ImportUser, ImportUserBuilder, PasswordEncoding, PasswordHashAlgorithm, SaltPosition,
+};
+pub use job_handle::JobHandle;
+pub use post_users_exports::{post_users_exports, ExportField, ExportFormat, UsersExportRequest};
+pub use post_users_imports::{
+    post_users_imports, UsersImportRequest, UsersImportRequestBuilder, MAX_IMPORT_FILE_SIZE,
+};
+pub use post_verification_email::{post_verification_email, VerificationEmailRequest};
@@ -0,0 +1,292 @@
+//! Job retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_job` function for fetching the state of a job, and
+//! `poll_job` for waiting until a job has finished. It wraps the GET /api/v2/jobs/{id}
+//! endpoint. See `JobHandle` for waiting with a timeout, backoff and cancellation.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/jobs/get-jobs-by-id)
+//! for more details about the get job endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+use super::job_handle::JobHandle;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// State of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+    #[serde(other)]
+    Other,
+}
+
+impl JobStatus {
+    /// Returns true for `Completed` and `Failed`.
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Pending => "pending",
+            Self::Processing => "processing",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Other => "other",
+        };
+        f.write_str(status)
+    }
+}
+
+/// Counts of users processed by an import job
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobSummary {
+    /// Number of users that could not be imported
+    #[serde(default)]
+    pub failed: u64,
+
+    /// Number of existing users that were updated
+    #[serde(default)]
+    pub updated: u64,
+
+    /// Number of new users that were inserted
+    #[serde(default)]
+    pub inserted: u64,
+
+    /// Total number of users in the file
+    #[serde(default)]
+    pub total: u64,
+}
+
+/// An Auth0 job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    /// The job's unique identifier
+    pub id: String,
+
+    /// Status of the job
+    pub status: JobStatus,
+
+    /// Type of the job (e.g. "users_import")
+    #[serde(rename = "type")]
+    pub job_type: String,
+
+    /// When the job was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// ID of the connection the job applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+
+    /// Name of the connection the job applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+
+    /// Customer defined ID of the job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+
+    /// Completion percentage of the job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage_done: Option<u32>,
+
+    /// Estimated seconds until the job completes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_left_seconds: Option<u64>,
+
+    /// Format of an export job ("json" or "csv")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Download URL of the file produced by a completed export job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// Counts of processed users, set once an import job has completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<JobSummary>,
+}
+
+impl Job {
+    /// Returns true once the job has completed or failed.
+    pub fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+}
+
+/// Fetches the current state of a job.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:users` scope
+/// * `job_id` - The ID of the job
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The job ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_job(domain: &Domain, token: &BearerToken, job_id: &str) -> Result<Job> {
+    // Validate job ID
+    if job_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Job ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and job ID
+    let endpoint = domain.to_url(&format!("/api/v2/jobs/{job_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the job
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<Job>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Polls a job at a fixed interval until it has completed or failed.
+///
+/// The returned job may have the status `Failed`; callers should check `status` and
+/// fetch the error report with `get_job_errors` when needed. A job in an unknown status is
+/// returned with the status `Other`. This is a shorthand for a `JobHandle` without backoff
+/// or timeout.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:users` scope
+/// * `job_id` - The ID of the job
+/// * `interval` - How long to wait between two status checks, at least 100ms
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if any status check fails, other than up to 10 consecutive
+/// checks answered with 429 Too Many Requests, which are retried.
+///
+/// # Rate Limiting
+///
+/// Every status check counts against the Management API rate limit, so the interval
+/// should not be shorter than a few seconds for large imports.
+pub async fn poll_job(
+    domain: &Domain,
+    token: &BearerToken,
+    job_id: &str,
+    interval: Duration,
+) -> Result<Job> {
+    JobHandle::new(domain, token, job_id)
+        .with_backoff(interval, interval)
+        .wait_until_finished()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_job_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "job_1",
+                    "status": "completed",
+                    "type": "users_import",
+                    "connection_id": "con_1",
+                    "summary": {"failed": 1, "updated": 0, "inserted": 9, "total": 10}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_job(&domain, &token, "job_1").await;
+        mock.assert_async().await;
+
+        let job = result.unwrap();
+        assert!(job.is_finished());
+        assert_eq!(job.summary.unwrap().inserted, 9);
+    }
+
+    #[tokio::test]
+    async fn test_poll_job_until_completed() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let pending = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "job_1", "status": "pending", "type": "users_import"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let poll = poll_job(&domain, &token, "job_1", Duration::from_millis(200));
+        let complete = async {
+            // Replace the pending response once the first check has been answered
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            pending.remove_async().await;
+            server
+                .mock("GET", "/api/v2/jobs/job_1")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"id": "job_1", "status": "failed", "type": "users_import"}"#)
+                .create_async()
+                .await
+        };
+
+        let (result, _completed) = tokio::join!(poll, complete);
+        assert_eq!(result.unwrap().status, JobStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_get_job_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_job(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,154 @@
+//! Job error report retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_job_errors` function for fetching the users a job failed
+//! to process. It wraps the GET /api/v2/jobs/{id}/errors endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/jobs/get-errors)
+//! for more details about the job errors endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A single validation or processing error for a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobErrorDetail {
+    /// Error code (e.g. "INVALID_FORMAT", "DUPLICATED_USER")
+    pub code: String,
+
+    /// Description of the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Path of the offending field in the user object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// A user that a job failed to process, with the reasons why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobError {
+    /// The user as it appeared in the job input
+    pub user: Value,
+
+    /// The errors reported for this user
+    #[serde(default)]
+    pub errors: Vec<JobErrorDetail>,
+}
+
+/// Fetches the error report of a job.
+///
+/// Returns an empty list when the job has no errors.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:users` scope
+/// * `job_id` - The ID of the job
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The job ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_job_errors(
+    domain: &Domain,
+    token: &BearerToken,
+    job_id: &str,
+) -> Result<Vec<JobError>> {
+    // Validate job ID
+    if job_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Job ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and job ID
+    let endpoint = domain.to_url(&format!("/api/v2/jobs/{job_id}/errors"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the error report
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<JobError>>()
+            .await
+            .map_err(Auth0Error::from),
+        StatusCode::NO_CONTENT => Ok(Vec::new()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_job_errors_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1/errors")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{
+                    "user": {"email": "broken@example.com"},
+                    "errors": [{"code": "INVALID_FORMAT", "message": "Object didn't pass validation", "path": "custom_password_hash.hash"}]
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_job_errors(&domain, &token, "job_1").await;
+        mock.assert_async().await;
+
+        let errors = result.unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].errors[0].code, "INVALID_FORMAT");
+        assert_eq!(errors[0].user["email"], "broken@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_get_job_errors_none() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1/errors")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = get_job_errors(&domain, &token, "job_1").await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_empty());
+    }
+}
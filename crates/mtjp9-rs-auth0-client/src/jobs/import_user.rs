@@ -0,0 +1,496 @@
+//! Typed Auth0 bulk import user format
+//!
+//! This module provides [`ImportUser`], which serializes to the user schema accepted by the
+//! users import job, and [`CustomPasswordHash`] for migrating password hashes produced by
+//! other systems.
+//!
+//! # Example
+//!
+//! ```
+//! use mtjp9_rs_auth0_client::jobs::{
+//!     CustomPasswordHash, HashEncoding, ImportUser, PasswordHashAlgorithm, SaltPosition,
+//! };
+//!
+//! let user = ImportUser::builder()
+//!     .email("jane@example.com")
+//!     .email_verified(true)
+//!     .custom_password_hash(
+//!         CustomPasswordHash::new(
+//!             PasswordHashAlgorithm::Sha256,
+//!             "5e884898da28047151d0e56f8dc6292773603d0d6aabbdd62a11ef721d1542d8",
+//!             HashEncoding::Hex,
+//!         )
+//!         .with_salt("pepper", HashEncoding::Utf8, SaltPosition::Suffix),
+//!     )
+//!     .build()
+//!     .unwrap();
+//!
+//! let file = serde_json::to_vec(&[user]).unwrap();
+//! assert!(!file.is_empty());
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 documentation](https://auth0.com/docs/manage-users/user-migration/bulk-user-import-database-schema-and-examples)
+//! for more details about the bulk import database schema.
+
+use crate::error::{Auth0Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Hashing algorithm of a migrated password
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordHashAlgorithm {
+    Argon2,
+    Bcrypt,
+    Hmac,
+    Ldap,
+    Md4,
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    Pbkdf2,
+}
+
+/// Encoding of a hash, key or salt value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashEncoding {
+    Base64,
+    Hex,
+    Utf8,
+}
+
+/// Digest used by HMAC hashes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HmacDigest {
+    Md4,
+    Md5,
+    Ripemd160,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Whirlpool,
+}
+
+/// Position of the salt relative to the password before hashing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SaltPosition {
+    Prefix,
+    Suffix,
+}
+
+/// Encoding of the password before hashing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordEncoding {
+    Ascii,
+    Utf8,
+    Utf16le,
+    Ucs2,
+    Latin1,
+    Binary,
+}
+
+/// An encoded value used as HMAC key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashKey {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<HashEncoding>,
+}
+
+/// The hashed password
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashValue {
+    /// The hash, or a PHC string for `argon2`, `bcrypt`, `ldap` and `pbkdf2`
+    pub value: String,
+
+    /// Encoding of `value`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<HashEncoding>,
+
+    /// Digest of `hmac` hashes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<HmacDigest>,
+
+    /// Key of `hmac` hashes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<HashKey>,
+}
+
+/// Salt added to the password before hashing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashSalt {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<HashEncoding>,
+    pub position: SaltPosition,
+}
+
+/// Encoding of the password before hashing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashPassword {
+    pub encoding: PasswordEncoding,
+}
+
+/// A password hash produced by another system
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomPasswordHash {
+    pub algorithm: PasswordHashAlgorithm,
+    pub hash: HashValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salt: Option<HashSalt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<HashPassword>,
+}
+
+impl CustomPasswordHash {
+    /// Creates a hash with an explicit encoding (for `md4`, `md5`, `sha*` and `hmac`).
+    pub fn new(
+        algorithm: PasswordHashAlgorithm,
+        value: impl Into<String>,
+        encoding: HashEncoding,
+    ) -> Self {
+        Self {
+            algorithm,
+            hash: HashValue {
+                value: value.into(),
+                encoding: Some(encoding),
+                digest: None,
+                key: None,
+            },
+            salt: None,
+            password: None,
+        }
+    }
+
+    /// Creates a hash from a self-describing string (PHC string for `argon2` and `pbkdf2`,
+    /// modular crypt format for `bcrypt`, RFC 2307 for `ldap`).
+    pub fn encoded(algorithm: PasswordHashAlgorithm, value: impl Into<String>) -> Self {
+        Self {
+            algorithm,
+            hash: HashValue {
+                value: value.into(),
+                encoding: None,
+                digest: None,
+                key: None,
+            },
+            salt: None,
+            password: None,
+        }
+    }
+
+    /// Creates an HMAC hash.
+    pub fn hmac(
+        value: impl Into<String>,
+        encoding: HashEncoding,
+        digest: HmacDigest,
+        key: impl Into<String>,
+        key_encoding: HashEncoding,
+    ) -> Self {
+        let mut hash = Self::new(PasswordHashAlgorithm::Hmac, value, encoding);
+        hash.hash.digest = Some(digest);
+        hash.hash.key = Some(HashKey {
+            value: key.into(),
+            encoding: Some(key_encoding),
+        });
+        hash
+    }
+
+    /// Sets the salt used when hashing.
+    pub fn with_salt(
+        mut self,
+        value: impl Into<String>,
+        encoding: HashEncoding,
+        position: SaltPosition,
+    ) -> Self {
+        self.salt = Some(HashSalt {
+            value: value.into(),
+            encoding: Some(encoding),
+            position,
+        });
+        self
+    }
+
+    /// Sets the encoding of the password before hashing.
+    pub fn with_password_encoding(mut self, encoding: PasswordEncoding) -> Self {
+        self.password = Some(HashPassword { encoding });
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.hash.value.is_empty() {
+            return Err(Auth0Error::InvalidRequest(
+                "Password hash value cannot be empty".to_string(),
+            ));
+        }
+
+        if self.algorithm == PasswordHashAlgorithm::Hmac
+            && (self.hash.digest.is_none() || self.hash.key.is_none())
+        {
+            return Err(Auth0Error::InvalidRequest(
+                "HMAC password hashes require a digest and a key".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A user in the Auth0 bulk import format
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportUser {
+    pub email: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+
+    /// ID of the user in the legacy system, kept as `auth0|<user_id>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+
+    /// A bcrypt hash (`$2a$`/`$2b$`) of the user's password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_hash: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_password_hash: Option<CustomPasswordHash>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_metadata: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<Value>,
+}
+
+impl ImportUser {
+    pub fn builder() -> ImportUserBuilder {
+        ImportUserBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ImportUserBuilder {
+    user: ImportUser,
+}
+
+impl ImportUserBuilder {
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.user.email = email.into();
+        self
+    }
+
+    pub fn email_verified(mut self, verified: bool) -> Self {
+        self.user.email_verified = Some(verified);
+        self
+    }
+
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user.user_id = Some(user_id.into());
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.user.username = Some(username.into());
+        self
+    }
+
+    pub fn given_name(mut self, given_name: impl Into<String>) -> Self {
+        self.user.given_name = Some(given_name.into());
+        self
+    }
+
+    pub fn family_name(mut self, family_name: impl Into<String>) -> Self {
+        self.user.family_name = Some(family_name.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.user.name = Some(name.into());
+        self
+    }
+
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.user.nickname = Some(nickname.into());
+        self
+    }
+
+    pub fn picture(mut self, picture: impl Into<String>) -> Self {
+        self.user.picture = Some(picture.into());
+        self
+    }
+
+    pub fn blocked(mut self, blocked: bool) -> Self {
+        self.user.blocked = Some(blocked);
+        self
+    }
+
+    pub fn password_hash(mut self, bcrypt_hash: impl Into<String>) -> Self {
+        self.user.password_hash = Some(bcrypt_hash.into());
+        self
+    }
+
+    pub fn custom_password_hash(mut self, hash: CustomPasswordHash) -> Self {
+        self.user.custom_password_hash = Some(hash);
+        self
+    }
+
+    pub fn app_metadata(mut self, metadata: Value) -> Self {
+        self.user.app_metadata = Some(metadata);
+        self
+    }
+
+    pub fn user_metadata(mut self, metadata: Value) -> Self {
+        self.user.user_metadata = Some(metadata);
+        self
+    }
+
+    pub fn build(self) -> Result<ImportUser> {
+        if self.user.email.is_empty() {
+            return Err(Auth0Error::InvalidRequest("Email is required".to_string()));
+        }
+        if !self.user.email.contains('@') {
+            return Err(Auth0Error::InvalidRequest(
+                "Invalid email format".to_string(),
+            ));
+        }
+
+        if self.user.password_hash.is_some() && self.user.custom_password_hash.is_some() {
+            return Err(Auth0Error::InvalidRequest(
+                "Only one of password_hash and custom_password_hash can be set".to_string(),
+            ));
+        }
+
+        if let Some(ref hash) = self.user.password_hash {
+            if !hash.starts_with("$2a$") && !hash.starts_with("$2b$") {
+                return Err(Auth0Error::InvalidRequest(
+                    "password_hash must be a bcrypt hash".to_string(),
+                ));
+            }
+        }
+
+        if let Some(ref hash) = self.user.custom_password_hash {
+            hash.validate()?;
+        }
+
+        Ok(self.user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_import_user_custom_hash_serialization() {
+        let user = ImportUser::builder()
+            .email("jane@example.com")
+            .user_id("legacy-42")
+            .custom_password_hash(
+                CustomPasswordHash::hmac(
+                    "a2V5ZWQ=",
+                    HashEncoding::Base64,
+                    HmacDigest::Sha256,
+                    "secret",
+                    HashEncoding::Utf8,
+                )
+                .with_salt("abcd", HashEncoding::Hex, SaltPosition::Prefix)
+                .with_password_encoding(PasswordEncoding::Utf8),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&user).unwrap(),
+            json!({
+                "email": "jane@example.com",
+                "user_id": "legacy-42",
+                "custom_password_hash": {
+                    "algorithm": "hmac",
+                    "hash": {
+                        "value": "a2V5ZWQ=",
+                        "encoding": "base64",
+                        "digest": "sha256",
+                        "key": {"value": "secret", "encoding": "utf8"}
+                    },
+                    "salt": {"value": "abcd", "encoding": "hex", "position": "prefix"},
+                    "password": {"encoding": "utf8"}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_import_user_encoded_hash() {
+        let user = ImportUser::builder()
+            .email("jane@example.com")
+            .custom_password_hash(CustomPasswordHash::encoded(
+                PasswordHashAlgorithm::Pbkdf2,
+                "$pbkdf2-sha512$i=100000,l=64$c2FsdA$aGFzaA",
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&user).unwrap()["custom_password_hash"],
+            json!({
+                "algorithm": "pbkdf2",
+                "hash": {"value": "$pbkdf2-sha512$i=100000,l=64$c2FsdA$aGFzaA"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_import_user_builder_validation() {
+        let missing_email = ImportUser::builder().build();
+        assert!(matches!(missing_email, Err(Auth0Error::InvalidRequest(_))));
+
+        let not_bcrypt = ImportUser::builder()
+            .email("jane@example.com")
+            .password_hash("5f4dcc3b5aa765d61d8327deb882cf99")
+            .build();
+        assert!(matches!(not_bcrypt, Err(Auth0Error::InvalidRequest(_))));
+
+        let hmac_without_key = ImportUser::builder()
+            .email("jane@example.com")
+            .custom_password_hash(CustomPasswordHash::new(
+                PasswordHashAlgorithm::Hmac,
+                "abcd",
+                HashEncoding::Hex,
+            ))
+            .build();
+        assert!(matches!(
+            hmac_without_key,
+            Err(Auth0Error::InvalidRequest(_))
+        ));
+    }
+}
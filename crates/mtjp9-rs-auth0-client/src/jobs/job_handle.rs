@@ -0,0 +1,354 @@
+//! Waiting for Auth0 jobs
+//!
+//! This module provides [`JobHandle`], which waits for any job (users imports, users
+//! exports, verification emails) to finish. Status checks back off exponentially, the wait
+//! can be bounded by a timeout, and it can be cancelled either by dropping the future or by
+//! passing a cancellation future.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     jobs::{JobHandle, JobStatus, UsersExportRequest, post_users_exports},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, time::Duration};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let job = post_users_exports(&domain, &token, UsersExportRequest::default()).await?;
+//!     let handle = JobHandle::new(&domain, &token, &job.id);
+//!
+//!     // Any future can cancel the wait, e.g. a shutdown signal
+//!     let shutdown = tokio::time::sleep(Duration::from_secs(60 * 60));
+//!
+//!     let job = handle
+//!         .wait_with_cancel(Duration::from_secs(15 * 60), shutdown)
+//!         .await?;
+//!     match job.status {
+//!         JobStatus::Completed => println!("Export ready at {:?}", job.location),
+//!         status => println!("Export job {} is {status}", handle.id()),
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use std::future::Future;
+use std::time::Duration;
+
+use super::get_job::{get_job, Job, JobStatus};
+
+// Default delay before the second status check
+const DEFAULT_INITIAL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Default upper bound of the delay between two status checks
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+// Lower bound of the delay between two status checks
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+// Consecutive rate limited status checks tolerated before the wait fails
+const MAX_RATE_LIMITED_CHECKS: u32 = 10;
+
+/// A handle to a running Auth0 job
+#[derive(Clone)]
+pub struct JobHandle {
+    domain: Domain,
+    token: BearerToken,
+    job_id: String,
+    initial_interval: Duration,
+    max_interval: Duration,
+}
+
+impl JobHandle {
+    /// Creates a handle for the job with the given ID.
+    pub fn new(domain: &Domain, token: &BearerToken, job_id: impl Into<String>) -> Self {
+        Self {
+            domain: domain.clone(),
+            token: token.clone(),
+            job_id: job_id.into(),
+            initial_interval: DEFAULT_INITIAL_INTERVAL,
+            max_interval: DEFAULT_MAX_INTERVAL,
+        }
+    }
+
+    /// Sets the delay before the second status check and the maximum delay between two
+    /// checks. The delay doubles after every check until it reaches the maximum.
+    ///
+    /// Delays shorter than 100ms, including zero, are raised to 100ms, and `max` is raised
+    /// to `initial` if it is shorter.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_interval = initial.max(MIN_INTERVAL);
+        self.max_interval = max.max(self.initial_interval);
+        self
+    }
+
+    /// The ID of the job.
+    pub fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// Fetches the current state of the job.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Auth0Error` if the status check fails.
+    pub async fn status(&self) -> Result<Job> {
+        get_job(&self.domain, &self.token, &self.job_id).await
+    }
+
+    /// Waits until the job has completed or failed.
+    ///
+    /// The returned job may have the status `Failed`. A job in a status this crate does not
+    /// know is returned as soon as it is seen, with the status `Other`, rather than polled
+    /// until the timeout. Dropping the returned future stops waiting; the job itself keeps
+    /// running in Auth0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Timeout` if the job has not finished within `timeout`, or the
+    /// error of a failed status check.
+    ///
+    /// # Rate Limiting
+    ///
+    /// Status checks answered with 429 Too Many Requests are retried after the next backoff
+    /// delay. After 10 consecutive rate limited checks the wait fails with
+    /// `Auth0Error::TooManyRequests`.
+    pub async fn wait(&self, timeout: Duration) -> Result<Job> {
+        match tokio::time::timeout(timeout, self.wait_until_finished()).await {
+            Ok(result) => result,
+            Err(_) => Err(Auth0Error::Timeout(format!(
+                "job {} did not finish within {:?}",
+                self.job_id, timeout
+            ))),
+        }
+    }
+
+    /// Waits until the job has finished, the timeout has elapsed or `cancel` resolves.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Cancelled` if `cancel` resolves first, and otherwise the same
+    /// errors as [`JobHandle::wait`].
+    pub async fn wait_with_cancel<F>(&self, timeout: Duration, cancel: F) -> Result<Job>
+    where
+        F: Future<Output = ()>,
+    {
+        tokio::select! {
+            result = self.wait(timeout) => result,
+            _ = cancel => Err(Auth0Error::Cancelled(format!(
+                "stopped waiting for job {}",
+                self.job_id
+            ))),
+        }
+    }
+
+    /// Waits without a deadline until the job has completed, failed or reached an unknown
+    /// status.
+    pub(super) async fn wait_until_finished(&self) -> Result<Job> {
+        let mut interval = self.initial_interval;
+        let mut rate_limited = 0;
+        loop {
+            match self.status().await {
+                Ok(job) if job.is_finished() || job.status == JobStatus::Other => return Ok(job),
+                Ok(_) => rate_limited = 0,
+                Err(Auth0Error::TooManyRequests(_)) if rate_limited < MAX_RATE_LIMITED_CHECKS => {
+                    rate_limited += 1;
+                }
+                Err(e) => return Err(e),
+            }
+
+            tokio::time::sleep(interval).await;
+            interval = interval.saturating_mul(2).min(self.max_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    const PENDING: &str =
+        r#"{"id": "job_1", "status": "processing", "type": "users_import", "percentage_done": 40}"#;
+
+    #[tokio::test]
+    async fn test_wait_retries_rate_limited_checks() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let rate_limited = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let completed = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "job_1", "status": "completed", "type": "users_import", "summary": {"inserted": 2, "total": 2}}"#,
+            )
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1")
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(20));
+        let job = handle.wait(Duration::from_secs(5)).await.unwrap();
+        rate_limited.assert_async().await;
+        completed.assert_async().await;
+
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(job.summary.unwrap().inserted, 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_timeout() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(PENDING)
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1")
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(40));
+        let result = handle.wait(Duration::from_millis(200)).await;
+
+        assert!(matches!(result, Err(Auth0Error::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_cancel() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(PENDING)
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1")
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(40));
+        let cancel = tokio::time::sleep(Duration::from_millis(100));
+        let result = handle
+            .wait_with_cancel(Duration::from_secs(60), cancel)
+            .await;
+
+        assert!(matches!(result, Err(Auth0Error::Cancelled(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_stops_on_error() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "The job does not exist"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1");
+        let result = handle.wait(Duration::from_secs(5)).await;
+        mock.assert_async().await;
+
+        assert!(matches!(
+            result,
+            Err(Auth0Error::UnexpectedResponse { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_unknown_status() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "job_1", "status": "abandoned", "type": "users_export"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1");
+        let job = handle.wait(Duration::from_secs(5)).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(job.status, JobStatus::Other);
+    }
+
+    #[tokio::test]
+    async fn test_wait_gives_up_when_rate_limited() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(429)
+            .expect(MAX_RATE_LIMITED_CHECKS as usize + 1)
+            .create_async()
+            .await;
+
+        let handle = JobHandle::new(&domain, &token, "job_1")
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(100));
+        let result = handle.wait_until_finished().await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::TooManyRequests(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_zero_backoff_is_clamped() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/jobs/job_1")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(PENDING)
+            .expect_at_most(3)
+            .create_async()
+            .await;
+
+        let handle =
+            JobHandle::new(&domain, &token, "job_1").with_backoff(Duration::ZERO, Duration::ZERO);
+        let result = handle.wait(Duration::from_millis(250)).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Timeout(_))));
+    }
+}
@@ -0,0 +1,215 @@
+//! Bulk user export for Auth0 Management API v2
+//!
+//! This module provides the `post_users_exports` function for exporting all users of a
+//! tenant or connection to a file. It wraps the POST /api/v2/jobs/users-exports endpoint.
+//! Once the job has completed, the file can be streamed with `stream_exported_users`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::TryStreamExt;
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     jobs::{ExportField, ExportFormat, UsersExportRequest, poll_job, post_users_exports, stream_exported_users},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, time::Duration};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = UsersExportRequest {
+//!         format: Some(ExportFormat::Json),
+//!         fields: Some(vec![
+//!             ExportField::new("user_id"),
+//!             ExportField::new("email"),
+//!             ExportField::new("app_metadata.plan").export_as("plan"),
+//!         ]),
+//!         ..Default::default()
+//!     };
+//!
+//!     let job = post_users_exports(&domain, &token, request).await?;
+//!     let job = poll_job(&domain, &token, &job.id, Duration::from_secs(10)).await?;
+//!
+//!     let location = job.location.ok_or_else(|| Auth0Error::InvalidRequest(format!("Export job {} failed", job.id)))?;
+//!     let mut users = Box::pin(stream_exported_users(&location).await?);
+//!     while let Some(user) = users.try_next().await? {
+//!         println!("{:?} {:?}", user.user_id, user.email);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/jobs/post-users-exports)
+//! for more details about the users export endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::get_job::Job;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Format of the exported file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Newline delimited JSON, one user per line
+    Json,
+
+    /// Comma separated values with a header line
+    Csv,
+}
+
+/// A user field to include in the export
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportField {
+    /// Name of the field, using dots for nested fields (e.g. "user_metadata.plan")
+    pub name: String,
+
+    /// Name of the field in the exported file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_as: Option<String>,
+}
+
+impl ExportField {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            export_as: None,
+        }
+    }
+
+    /// Renames the field in the exported file.
+    pub fn export_as(mut self, export_as: impl Into<String>) -> Self {
+        self.export_as = Some(export_as.into());
+        self
+    }
+}
+
+/// Request body for a users export job
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UsersExportRequest {
+    /// Only export the users of this connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+
+    /// Format of the exported file. Defaults to CSV.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ExportFormat>,
+
+    /// Maximum number of users to export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Fields to include. Defaults to a basic set of profile fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<ExportField>>,
+}
+
+/// Starts a job that exports users to a gzipped file.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:users` scope
+/// * `request` - The export options
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn post_users_exports(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UsersExportRequest,
+) -> Result<Job> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/jobs/users-exports");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to start the export job
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::ACCEPTED | StatusCode::OK => {
+            response.json::<Job>().await.map_err(Auth0Error::from)
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_post_users_exports_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UsersExportRequest {
+            connection_id: Some("con_1".to_string()),
+            format: Some(ExportFormat::Json),
+            fields: Some(vec![
+                ExportField::new("email"),
+                ExportField::new("app_metadata.plan").export_as("plan"),
+            ]),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/jobs/users-exports")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{
+                    "connection_id": "con_1",
+                    "format": "json",
+                    "fields": [{"name": "email"}, {"name": "app_metadata.plan", "export_as": "plan"}]
+                }"#
+                .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "job_2", "status": "pending", "type": "users_export", "format": "json"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = post_users_exports(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let job = result.unwrap();
+        assert_eq!(job.job_type, "users_export");
+        assert_eq!(job.format.as_deref(), Some("json"));
+    }
+}
@@ -0,0 +1,309 @@
+//! Bulk user import for Auth0 Management API v2
+//!
+//! This module provides the `post_users_imports` function for importing users into a
+//! database connection from a JSON file. It wraps the POST /api/v2/jobs/users-imports
+//! endpoint, which takes a `multipart/form-data` body.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     jobs::{ImportUser, UsersImportRequest, get_job_errors, poll_job, post_users_imports},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, time::Duration};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let users = vec![
+//!         ImportUser::builder()
+//!             .email("jane@example.com")
+//!             .password_hash("$2b$10$C9hlv7bM8kk8nN7VY0N9XeFGD.5mLPsCyXJ4nb1xGqUoZDzDN5T5u")
+//!             .build()?,
+//!     ];
+//!
+//!     let request = UsersImportRequest::builder()
+//!         .connection_id("con_legacy")
+//!         .users(&users)?
+//!         .upsert(true)
+//!         .build()?;
+//!
+//!     let job = post_users_imports(&domain, &token, request).await?;
+//!     let job = poll_job(&domain, &token, &job.id, Duration::from_secs(5)).await?;
+//!
+//!     for error in get_job_errors(&domain, &token, &job.id).await? {
+//!         println!("{}: {:?}", error.user, error.errors);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/jobs/post-users-imports)
+//! for more details about the users import endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{
+    multipart::{Form, Part},
+    Client, StatusCode,
+};
+use std::time::Duration;
+
+use super::get_job::Job;
+use super::import_user::ImportUser;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum size of a users import file accepted by Auth0 (500KB)
+pub const MAX_IMPORT_FILE_SIZE: usize = 500 * 1024;
+
+/// Request for a users import job
+#[derive(Debug, Clone)]
+pub struct UsersImportRequest {
+    /// ID of the database connection to import the users into
+    pub connection_id: String,
+
+    /// JSON file containing an array of users in the Auth0 import format
+    pub users: Vec<u8>,
+
+    /// Whether existing users are updated instead of reported as duplicates
+    pub upsert: Option<bool>,
+
+    /// Customer defined ID of the job
+    pub external_id: Option<String>,
+
+    /// Whether an email is sent to tenant owners once the job has completed
+    pub send_completion_email: Option<bool>,
+}
+
+impl UsersImportRequest {
+    pub fn builder() -> UsersImportRequestBuilder {
+        UsersImportRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UsersImportRequestBuilder {
+    connection_id: Option<String>,
+    users: Option<Vec<u8>>,
+    upsert: Option<bool>,
+    external_id: Option<String>,
+    send_completion_email: Option<bool>,
+}
+
+impl UsersImportRequestBuilder {
+    pub fn connection_id(mut self, connection_id: impl Into<String>) -> Self {
+        self.connection_id = Some(connection_id.into());
+        self
+    }
+
+    /// Serializes typed users into the import file.
+    pub fn users(mut self, users: &[ImportUser]) -> Result<Self> {
+        let file = serde_json::to_vec(users)
+            .map_err(|e| Auth0Error::InvalidRequest(format!("Failed to serialize users: {e}")))?;
+        self.users = Some(file);
+        Ok(self)
+    }
+
+    /// Uses an existing import file as is.
+    pub fn users_json(mut self, file: impl Into<Vec<u8>>) -> Self {
+        self.users = Some(file.into());
+        self
+    }
+
+    pub fn upsert(mut self, upsert: bool) -> Self {
+        self.upsert = Some(upsert);
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn send_completion_email(mut self, send: bool) -> Self {
+        self.send_completion_email = Some(send);
+        self
+    }
+
+    pub fn build(self) -> Result<UsersImportRequest> {
+        let connection_id = self
+            .connection_id
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| Auth0Error::InvalidRequest("Connection ID is required".to_string()))?;
+
+        let users = self
+            .users
+            .ok_or_else(|| Auth0Error::InvalidRequest("Users file is required".to_string()))?;
+
+        if users.len() > MAX_IMPORT_FILE_SIZE {
+            return Err(Auth0Error::InvalidRequest(format!(
+                "Users file is {} bytes, the maximum is {MAX_IMPORT_FILE_SIZE} bytes",
+                users.len()
+            )));
+        }
+
+        Ok(UsersImportRequest {
+            connection_id,
+            users,
+            upsert: self.upsert,
+            external_id: self.external_id,
+            send_completion_email: self.send_completion_email,
+        })
+    }
+}
+
+/// Starts a job that imports users into a database connection.
+///
+/// The job runs asynchronously; use `poll_job` to wait for it and `get_job_errors` to
+/// fetch the users that could not be imported.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:users` scope
+/// * `request` - The import file and job options
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+///
+/// # Rate Limiting
+///
+/// Auth0 allows only a limited number of concurrent import jobs per tenant and answers
+/// with 429 when the limit is reached.
+pub async fn post_users_imports(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UsersImportRequest,
+) -> Result<Job> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/jobs/users-imports");
+
+    // Build the multipart form with the users file and job options
+    let users = Part::bytes(request.users)
+        .file_name("users.json")
+        .mime_str("application/json")?;
+    let mut form = Form::new()
+        .part("users", users)
+        .text("connection_id", request.connection_id);
+    if let Some(upsert) = request.upsert {
+        form = form.text("upsert", upsert.to_string());
+    }
+    if let Some(external_id) = request.external_id {
+        form = form.text("external_id", external_id);
+    }
+    if let Some(send) = request.send_completion_email {
+        form = form.text("send_completion_email", send.to_string());
+    }
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to start the import job
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .multipart(form)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::ACCEPTED | StatusCode::OK => {
+            response.json::<Job>().await.map_err(Auth0Error::from)
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_post_users_imports_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let users = vec![ImportUser::builder()
+            .email("jane@example.com")
+            .build()
+            .unwrap()];
+        let request = UsersImportRequest::builder()
+            .connection_id("con_legacy")
+            .users(&users)
+            .unwrap()
+            .upsert(true)
+            .external_id("migration-1")
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/api/v2/jobs/users-imports")
+            .match_header("Authorization", "Bearer test-token")
+            .match_header(
+                "Content-Type",
+                Matcher::Regex("^multipart/form-data; boundary=".to_string()),
+            )
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(r#"name="users"; filename="users.json""#.to_string()),
+                Matcher::Regex(r#"\[\{"email":"jane@example.com"\}\]"#.to_string()),
+                Matcher::Regex(r#"name="connection_id"\r\n\r\ncon_legacy"#.to_string()),
+                Matcher::Regex(r#"name="upsert"\r\n\r\ntrue"#.to_string()),
+                Matcher::Regex(r#"name="external_id"\r\n\r\nmigration-1"#.to_string()),
+            ]))
+            .with_status(202)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "job_1", "status": "pending", "type": "users_import", "connection_id": "con_legacy", "external_id": "migration-1"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = post_users_imports(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let job = result.unwrap();
+        assert_eq!(job.id, "job_1");
+        assert_eq!(job.job_type, "users_import");
+    }
+
+    #[test]
+    fn test_users_import_request_size_limit() {
+        let request = UsersImportRequest::builder()
+            .connection_id("con_legacy")
+            .users_json(vec![b' '; MAX_IMPORT_FILE_SIZE + 1])
+            .build();
+
+        match request {
+            Err(Auth0Error::InvalidRequest(msg)) => assert!(msg.contains("maximum")),
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_users_import_request_missing_connection() {
+        let request = UsersImportRequest::builder().users_json("[]").build();
+        assert!(matches!(request, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
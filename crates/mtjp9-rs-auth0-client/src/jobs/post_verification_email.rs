@@ -0,0 +1,180 @@
+//! Verification email job for Auth0 Management API v2
+//!
+//! This module provides the `post_verification_email` function for sending the email
+//! verification email to a user. It wraps the POST /api/v2/jobs/verification-email endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     jobs::{VerificationEmailRequest, post_verification_email},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = VerificationEmailRequest {
+//!         user_id: "auth0|507f1f77bcf86cd799439011".to_string(),
+//!         ..Default::default()
+//!     };
+//!
+//!     let job = post_verification_email(&domain, &token, request).await?;
+//!     println!("Verification email job {} is {}", job.id, job.status);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/jobs/post-verification-email)
+//! for more details about the verification email endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    tickets::VerificationIdentity,
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::get_job::Job;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for sending a verification email
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VerificationEmailRequest {
+    /// The user ID of the user to send the email to
+    pub user_id: String,
+
+    /// The client ID whose email template and redirect settings are used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// The secondary identity whose email is verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<VerificationIdentity>,
+
+    /// The organization ID used in the email template and redirect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+}
+
+/// Sends an email verification email to a user.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:users` scope
+/// * `request` - The user and options of the email
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The user ID is empty or the identity is incomplete
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn post_verification_email(
+    domain: &Domain,
+    token: &BearerToken,
+    request: VerificationEmailRequest,
+) -> Result<Job> {
+    // Validate user ID and identity
+    if request.user_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "User ID cannot be empty".to_string(),
+        ));
+    }
+    if let Some(identity) = &request.identity {
+        identity.validate()?;
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/jobs/verification-email");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to start the verification email job
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => {
+            response.json::<Job>().await.map_err(Auth0Error::from)
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_post_verification_email_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = VerificationEmailRequest {
+            user_id: "google-oauth2|1234".to_string(),
+            identity: Some(VerificationIdentity::new("5678", "auth0")),
+            organization_id: Some("org_1".to_string()),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/jobs/verification-email")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{
+                    "user_id": "google-oauth2|1234",
+                    "identity": {"user_id": "5678", "provider": "auth0"},
+                    "organization_id": "org_1"
+                }"#
+                .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "job_3", "status": "pending", "type": "verification_email", "created_at": "2025-08-06T00:00:00.000Z"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = post_verification_email(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().job_type, "verification_email");
+    }
+
+    #[tokio::test]
+    async fn test_post_verification_email_empty_user_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = post_verification_email(&domain, &token, Default::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -1,10 +1,26 @@
 use rand::{distr::Alphanumeric, Rng};
 
+pub mod actions;
+pub mod attack_protection;
+pub mod branding;
+pub mod client_grants;
+pub mod clients;
+pub mod connections;
+pub mod custom_domains;
 pub mod dbconnections;
 pub mod domain;
+pub mod email_templates;
+pub mod emails;
 pub mod error;
+pub mod guardian;
+pub mod jobs;
+pub mod log_streams;
+pub mod logs;
 pub mod oauth;
 pub mod organizations;
+pub mod prompts;
+pub mod resource_servers;
+pub mod tenants;
 pub mod tickets;
 pub mod token;
 pub mod users;
@@ -0,0 +1,22 @@
+//! Log stream management functionality for Auth0
+//!
+//! This module provides functions for managing log streams through the Auth0 Management
+//! API v2, and a receiver for the requests sent by `http` log streams.
+
+mod create_log_stream;
+mod delete_log_stream;
+mod get_log_stream;
+mod list_log_streams;
+mod update_log_stream;
+mod webhook;
+
+pub use create_log_stream::{
+    create_log_stream, CreateLogStreamRequest, DatadogSink, EventBridgeSink, HttpContentFormat,
+    HttpCustomHeader, HttpSink, LogStream, LogStreamFilter, LogStreamSink, LogStreamStatus,
+    SplunkSink, SumoSink,
+};
+pub use delete_log_stream::delete_log_stream;
+pub use get_log_stream::get_log_stream;
+pub use list_log_streams::list_log_streams;
+pub use update_log_stream::{update_log_stream, UpdateLogStreamRequest};
+pub use webhook::{parse_log_stream_batch, LogStreamReceiver};
@@ -0,0 +1,539 @@
+//! Log stream creation for Auth0 Management API v2
+//!
+//! This module provides the `create_log_stream` function for streaming tenant logs to an
+//! external service, along with the typed log stream model. It wraps the
+//! POST /api/v2/log-streams endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     log_streams::{CreateLogStreamRequest, HttpContentFormat, HttpSink, LogStreamFilter, create_log_stream},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let sink = HttpSink {
+//!         http_content_format: Some(HttpContentFormat::JsonArray),
+//!         http_authorization: Some("Bearer receiver-secret".to_string()),
+//!         ..HttpSink::new("https://siem.example.com/auth0/logs")
+//!     };
+//!     let request = CreateLogStreamRequest {
+//!         filters: Some(vec![LogStreamFilter::category("auth.login.fail")]),
+//!         ..CreateLogStreamRequest::new("siem", sink)
+//!     };
+//!
+//!     let stream = create_log_stream(&domain, &token, request).await?;
+//!     println!("Created log stream {}", stream.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/log-streams/post-log-streams)
+//! for more details about the create log stream endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Status of a log stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStreamStatus {
+    Active,
+    Paused,
+
+    /// Set by Auth0 after repeated delivery failures
+    Suspended,
+
+    #[serde(other)]
+    Other,
+}
+
+/// Format of the batches sent by an `http` log stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpContentFormat {
+    /// A JSON array of log entries per request
+    JsonArray,
+
+    /// One JSON log entry per line
+    JsonLines,
+
+    /// A single JSON log entry per request
+    JsonObject,
+}
+
+/// A header added to every request of an `http` log stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpCustomHeader {
+    pub header: String,
+    pub value: String,
+}
+
+/// Sink of an `http` log stream, also used for custom webhooks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpSink {
+    /// URL the log batches are posted to
+    pub http_endpoint: String,
+
+    /// Content type of the requests (e.g. "application/json")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_type: Option<String>,
+
+    /// Format of the batches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_format: Option<HttpContentFormat>,
+
+    /// Value of the `Authorization` header sent with every request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_authorization: Option<String>,
+
+    /// Additional headers sent with every request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_custom_headers: Option<Vec<HttpCustomHeader>>,
+}
+
+impl HttpSink {
+    pub fn new(http_endpoint: impl Into<String>) -> Self {
+        Self {
+            http_endpoint: http_endpoint.into(),
+            http_content_type: None,
+            http_content_format: None,
+            http_authorization: None,
+            http_custom_headers: None,
+        }
+    }
+}
+
+/// Sink of an `eventbridge` log stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventBridgeSink {
+    /// AWS account the partner event source is created in
+    pub aws_account_id: String,
+
+    /// AWS region of the event bus (e.g. "eu-west-1")
+    pub aws_region: String,
+
+    /// Name of the partner event source, set by Auth0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_partner_event_source: Option<String>,
+}
+
+/// Sink of a `datadog` log stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatadogSink {
+    /// Datadog site region (e.g. "us", "eu", "us3")
+    pub datadog_region: String,
+
+    /// Datadog API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datadog_api_key: Option<String>,
+}
+
+/// Sink of a `splunk` log stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplunkSink {
+    /// Domain of the Splunk HTTP Event Collector
+    pub splunk_domain: String,
+
+    /// HTTP Event Collector token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_token: Option<String>,
+
+    /// Port of the HTTP Event Collector
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_port: Option<String>,
+
+    /// Whether the certificate of the collector is verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_secure: Option<bool>,
+}
+
+/// Sink of a `sumo` log stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SumoSink {
+    /// URL of the Sumo Logic HTTP source
+    pub sumo_source_address: String,
+}
+
+/// Destination of a log stream
+///
+/// When part of a [`LogStream`], the variant is chosen from the stream's type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LogStreamSink {
+    Http(HttpSink),
+    EventBridge(EventBridgeSink),
+    Datadog(DatadogSink),
+    Splunk(SplunkSink),
+    Sumo(SumoSink),
+
+    /// Sink of any other stream type
+    Other(Value),
+}
+
+impl LogStreamSink {
+    /// Parses a raw sink according to the log stream type.
+    pub fn from_type(
+        stream_type: &str,
+        value: Value,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        match stream_type {
+            "http" => Ok(Self::Http(serde_json::from_value(value)?)),
+            "eventbridge" => Ok(Self::EventBridge(serde_json::from_value(value)?)),
+            "datadog" => Ok(Self::Datadog(serde_json::from_value(value)?)),
+            "splunk" => Ok(Self::Splunk(serde_json::from_value(value)?)),
+            "sumo" => Ok(Self::Sumo(serde_json::from_value(value)?)),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+
+    /// The log stream type of a typed sink.
+    pub fn stream_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Http(_) => Some("http"),
+            Self::EventBridge(_) => Some("eventbridge"),
+            Self::Datadog(_) => Some("datadog"),
+            Self::Splunk(_) => Some("splunk"),
+            Self::Sumo(_) => Some("sumo"),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<HttpSink> for LogStreamSink {
+    fn from(sink: HttpSink) -> Self {
+        Self::Http(sink)
+    }
+}
+
+impl From<EventBridgeSink> for LogStreamSink {
+    fn from(sink: EventBridgeSink) -> Self {
+        Self::EventBridge(sink)
+    }
+}
+
+impl From<DatadogSink> for LogStreamSink {
+    fn from(sink: DatadogSink) -> Self {
+        Self::Datadog(sink)
+    }
+}
+
+impl From<SplunkSink> for LogStreamSink {
+    fn from(sink: SplunkSink) -> Self {
+        Self::Splunk(sink)
+    }
+}
+
+impl From<SumoSink> for LogStreamSink {
+    fn from(sink: SumoSink) -> Self {
+        Self::Sumo(sink)
+    }
+}
+
+/// Restricts a log stream to some categories of events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogStreamFilter {
+    /// Type of the filter, currently always "category"
+    #[serde(rename = "type")]
+    pub filter_type: String,
+
+    /// Name of the event category (e.g. "auth.login.fail")
+    pub name: String,
+}
+
+impl LogStreamFilter {
+    /// A filter on an event category.
+    pub fn category(name: impl Into<String>) -> Self {
+        Self {
+            filter_type: "category".to_string(),
+            name: name.into(),
+        }
+    }
+}
+
+/// Request body for creating a log stream
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLogStreamRequest {
+    /// The name of the log stream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The type of the log stream (e.g. "http", "datadog")
+    #[serde(rename = "type")]
+    pub stream_type: String,
+
+    /// The destination of the logs
+    pub sink: LogStreamSink,
+
+    /// Only stream events of these categories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<LogStreamFilter>>,
+
+    /// Whether the stream is delivered with priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_priority: Option<bool>,
+
+    /// Log ID to start streaming from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_from: Option<String>,
+}
+
+impl CreateLogStreamRequest {
+    /// Creates a request for a typed sink, using the stream type of the sink.
+    pub fn new(name: impl Into<String>, sink: impl Into<LogStreamSink>) -> Self {
+        let sink = sink.into();
+        Self {
+            name: Some(name.into()),
+            stream_type: sink.stream_type().unwrap_or_default().to_string(),
+            sink,
+            filters: None,
+            is_priority: None,
+            start_from: None,
+        }
+    }
+}
+
+/// A log stream as returned by the Management API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "RawLogStream", rename_all = "camelCase")]
+pub struct LogStream {
+    /// The log stream's unique identifier
+    pub id: String,
+
+    /// The name of the log stream
+    pub name: String,
+
+    /// The type of the log stream
+    #[serde(rename = "type")]
+    pub stream_type: String,
+
+    /// Whether the log stream delivers events
+    pub status: LogStreamStatus,
+
+    /// The destination of the logs
+    pub sink: LogStreamSink,
+
+    /// Category filters of the log stream
+    pub filters: Vec<LogStreamFilter>,
+
+    /// Whether the stream is delivered with priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_priority: Option<bool>,
+}
+
+/// Wire representation of a log stream, before its sink is typed by stream type
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawLogStream {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    stream_type: String,
+    status: LogStreamStatus,
+    #[serde(default)]
+    sink: Value,
+    #[serde(default)]
+    filters: Vec<LogStreamFilter>,
+    is_priority: Option<bool>,
+}
+
+impl TryFrom<RawLogStream> for LogStream {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawLogStream) -> std::result::Result<Self, Self::Error> {
+        let sink = LogStreamSink::from_type(&raw.stream_type, raw.sink)?;
+
+        Ok(Self {
+            id: raw.id,
+            name: raw.name,
+            stream_type: raw.stream_type,
+            status: raw.status,
+            sink,
+            filters: raw.filters,
+            is_priority: raw.is_priority,
+        })
+    }
+}
+
+/// Creates a log stream.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:log_streams` scope
+/// * `request` - The log stream configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The stream type is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn create_log_stream(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateLogStreamRequest,
+) -> Result<LogStream> {
+    // Validate stream type
+    if request.stream_type.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Log stream type cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/log-streams");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the log stream
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED => {
+            response.json::<LogStream>().await.map_err(Auth0Error::from)
+        }
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_create_log_stream_http() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let sink = HttpSink {
+            http_content_format: Some(HttpContentFormat::JsonLines),
+            http_authorization: Some("Bearer secret".to_string()),
+            ..HttpSink::new("https://siem.example.com/logs")
+        };
+        let request = CreateLogStreamRequest {
+            filters: Some(vec![LogStreamFilter::category("auth.login.fail")]),
+            ..CreateLogStreamRequest::new("siem", sink)
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/log-streams")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{
+                    "name": "siem",
+                    "type": "http",
+                    "sink": {
+                        "httpEndpoint": "https://siem.example.com/logs",
+                        "httpContentFormat": "JSONLINES",
+                        "httpAuthorization": "Bearer secret"
+                    },
+                    "filters": [{"type": "category", "name": "auth.login.fail"}]
+                }"#
+                .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "lst_1",
+                    "name": "siem",
+                    "type": "http",
+                    "status": "active",
+                    "sink": {
+                        "httpEndpoint": "https://siem.example.com/logs",
+                        "httpContentFormat": "JSONLINES",
+                        "httpContentType": "application/json"
+                    },
+                    "filters": [{"type": "category", "name": "auth.login.fail"}],
+                    "isPriority": false
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = create_log_stream(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let stream = result.unwrap();
+        assert_eq!(stream.status, LogStreamStatus::Active);
+        match stream.sink {
+            LogStreamSink::Http(sink) => {
+                assert_eq!(sink.http_content_format, Some(HttpContentFormat::JsonLines))
+            }
+            other => panic!("Expected http sink, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_log_stream_sink_by_type() {
+        let stream: LogStream = serde_json::from_str(
+            r#"{
+                "id": "lst_2",
+                "name": "events",
+                "type": "eventbridge",
+                "status": "suspended",
+                "sink": {"awsAccountId": "123456789012", "awsRegion": "eu-west-1", "awsPartnerEventSource": "aws.partner/auth0.com/x"}
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(stream.sink, LogStreamSink::EventBridge(_)));
+        assert_eq!(stream.status, LogStreamStatus::Suspended);
+
+        let stream: LogStream = serde_json::from_str(
+            r#"{"id": "lst_3", "name": "mixpanel", "type": "mixpanel", "status": "paused", "sink": {"mixpanelRegion": "us"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(stream.sink, LogStreamSink::Other(_)));
+    }
+
+    #[tokio::test]
+    async fn test_create_log_stream_empty_type() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateLogStreamRequest::new("custom", LogStreamSink::Other(Value::Null));
+        let result = create_log_stream(&domain, &token, request).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,112 @@
+//! Log stream retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_log_stream` function for fetching a single log stream.
+//! It wraps the GET /api/v2/log-streams/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/log-streams/get-log-streams-by-id)
+//! for more details about the get log stream endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_log_stream::LogStream;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches a log stream by its ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:log_streams` scope
+/// * `log_stream_id` - The ID of the log stream
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The log stream ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_log_stream(
+    domain: &Domain,
+    token: &BearerToken,
+    log_stream_id: &str,
+) -> Result<LogStream> {
+    // Validate log stream ID
+    if log_stream_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Log stream ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and log stream ID
+    let endpoint = domain.to_url(&format!("/api/v2/log-streams/{log_stream_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the log stream
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<LogStream>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_streams::LogStreamSink;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_log_stream_datadog() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/log-streams/lst_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "lst_1", "name": "datadog", "type": "datadog", "status": "active", "sink": {"datadogRegion": "eu"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_log_stream(&domain, &token, "lst_1").await;
+        mock.assert_async().await;
+
+        match result.unwrap().sink {
+            LogStreamSink::Datadog(sink) => assert_eq!(sink.datadog_region, "eu"),
+            other => panic!("Expected datadog sink, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_log_stream_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_log_stream(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,96 @@
+//! Log stream listing for Auth0 Management API v2
+//!
+//! This module provides the `list_log_streams` function for listing the log streams of a
+//! tenant. It wraps the GET /api/v2/log-streams endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/log-streams/get-log-streams)
+//! for more details about the list log streams endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_log_stream::LogStream;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Lists the log streams of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:log_streams` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_log_streams(domain: &Domain, token: &BearerToken) -> Result<Vec<LogStream>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/log-streams");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the log streams
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<LogStream>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_streams::LogStreamSink;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_list_log_streams_mixed_types() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/log-streams")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "lst_1", "name": "splunk", "type": "splunk", "status": "active", "sink": {"splunkDomain": "splunk.example.com", "splunkPort": "8088", "splunkSecure": true}},
+                    {"id": "lst_2", "name": "sumo", "type": "sumo", "status": "paused", "sink": {"sumoSourceAddress": "https://endpoint.sumologic.com/receiver/v1/http/x"}}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_log_streams(&domain, &token).await;
+        mock.assert_async().await;
+
+        let streams = result.unwrap();
+        assert_eq!(streams.len(), 2);
+        assert!(matches!(streams[0].sink, LogStreamSink::Splunk(_)));
+        assert!(matches!(streams[1].sink, LogStreamSink::Sumo(_)));
+    }
+}
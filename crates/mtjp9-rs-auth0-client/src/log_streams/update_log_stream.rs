@@ -0,0 +1,149 @@
+//! Log stream updates for Auth0 Management API v2
+//!
+//! This module provides the `update_log_stream` function for changing a log stream, for
+//! example to pause it or to resume a suspended stream. It wraps the
+//! PATCH /api/v2/log-streams/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/log-streams/patch-log-streams-by-id)
+//! for more details about the update log stream endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::create_log_stream::{LogStream, LogStreamFilter, LogStreamSink, LogStreamStatus};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating a log stream
+///
+/// All fields are optional. Only fields that are provided will be updated. The stream
+/// type cannot be changed.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLogStreamRequest {
+    /// The name of the log stream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Set to `Active` to resume a paused or suspended stream, or `Paused` to pause it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LogStreamStatus>,
+
+    /// The destination of the logs, of the same type as the stream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sink: Option<LogStreamSink>,
+
+    /// Only stream events of these categories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<LogStreamFilter>>,
+
+    /// Whether the stream is delivered with priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_priority: Option<bool>,
+}
+
+/// Updates a log stream.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:log_streams` scope
+/// * `log_stream_id` - The ID of the log stream to update
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The log stream ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_log_stream(
+    domain: &Domain,
+    token: &BearerToken,
+    log_stream_id: &str,
+    request: UpdateLogStreamRequest,
+) -> Result<LogStream> {
+    // Validate log stream ID
+    if log_stream_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Log stream ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and log stream ID
+    let endpoint = domain.to_url(&format!("/api/v2/log-streams/{log_stream_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the log stream
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<LogStream>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_log_stream_resume() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UpdateLogStreamRequest {
+            status: Some(LogStreamStatus::Active),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/log-streams/lst_1")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(r#"{"status": "active"}"#.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"id": "lst_1", "name": "sumo", "type": "sumo", "status": "active", "sink": {"sumoSourceAddress": "https://endpoint.sumologic.com/x"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_log_stream(&domain, &token, "lst_1", request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().status, LogStreamStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_update_log_stream_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result =
+            update_log_stream(&domain, &token, "", UpdateLogStreamRequest::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,207 @@
+//! Receiving `http` log streams
+//!
+//! This module provides `LogStreamReceiver`, which checks the `Authorization` header of a
+//! request sent by an `http` log stream and parses its batch of log entries into
+//! [`LogEvent`]s. It does not depend on a web framework; handlers pass in the header value
+//! and the raw request body.
+//!
+//! All batch formats are accepted: a JSON array (`JSONARRAY`), one entry per line
+//! (`JSONLINES`) and a single entry (`JSONOBJECT`).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     log_streams::LogStreamReceiver,
+//!     error::Auth0Error,
+//! };
+//!
+//! // Called by the web framework with the `Authorization` header and the request body
+//! fn handle_log_batch(
+//!     receiver: &LogStreamReceiver,
+//!     authorization: Option<&str>,
+//!     body: &[u8],
+//! ) -> Result<(), Auth0Error> {
+//!     for event in receiver.receive(authorization, body)? {
+//!         println!("{} {}", event.log_id, event.event_type.name());
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let receiver = LogStreamReceiver::new("Bearer receiver-secret");
+//! handle_log_batch(&receiver, Some("Bearer receiver-secret"), b"[]").unwrap();
+//! ```
+
+use crate::error::{Auth0Error, Result};
+use crate::logs::LogEvent;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// An entry of a log stream batch
+#[derive(Deserialize)]
+struct LogStreamEntry {
+    log_id: String,
+    data: Value,
+}
+
+/// Validates and parses requests sent by an `http` log stream
+#[derive(Clone)]
+pub struct LogStreamReceiver {
+    authorization: String,
+}
+
+impl LogStreamReceiver {
+    /// Creates a receiver expecting the `httpAuthorization` value configured on the
+    /// log stream.
+    pub fn new(authorization: impl Into<String>) -> Self {
+        Self {
+            authorization: authorization.into(),
+        }
+    }
+
+    /// Checks the `Authorization` header of a request.
+    ///
+    /// The comparison takes the same time wherever the values differ, so the expected
+    /// value cannot be guessed byte by byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Unauthorized` if the header is missing or does not match.
+    pub fn verify(&self, authorization: Option<&str>) -> Result<()> {
+        match authorization {
+            Some(received) if constant_time_eq(received, &self.authorization) => Ok(()),
+            Some(_) => Err(Auth0Error::Unauthorized(
+                "log stream authorization does not match".to_string(),
+            )),
+            None => Err(Auth0Error::Unauthorized(
+                "log stream authorization is missing".to_string(),
+            )),
+        }
+    }
+
+    /// Checks the `Authorization` header and parses the request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Unauthorized` if the header does not match, and
+    /// `Auth0Error::Decode` if the body is not a valid batch.
+    pub fn receive(&self, authorization: Option<&str>, body: &[u8]) -> Result<Vec<LogEvent>> {
+        self.verify(authorization)?;
+        parse_log_stream_batch(body)
+    }
+}
+
+impl std::fmt::Debug for LogStreamReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogStreamReceiver")
+            .field("authorization", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Parses the body of a request sent by an `http` log stream.
+///
+/// # Errors
+///
+/// Returns `Auth0Error::Decode` if the body is not a JSON array, JSON lines or a single
+/// JSON object of log stream entries.
+pub fn parse_log_stream_batch(body: &[u8]) -> Result<Vec<LogEvent>> {
+    let body = std::str::from_utf8(body)
+        .map_err(|e| Auth0Error::Decode(format!("log stream batch is not UTF-8: {e}")))?
+        .trim();
+
+    let entries: Vec<LogStreamEntry> = if body.starts_with('[') {
+        serde_json::from_str(body).map_err(batch_error)?
+    } else {
+        body.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_str(line).map_err(batch_error))
+            .collect::<Result<_>>()?
+    };
+
+    entries.into_iter().map(into_log_event).collect()
+}
+
+fn into_log_event(entry: LogStreamEntry) -> Result<LogEvent> {
+    let mut data = entry.data;
+    // The entry's log_id is authoritative; some event types omit it from `data`
+    if let Value::Object(fields) = &mut data {
+        fields.insert("log_id".to_string(), Value::String(entry.log_id));
+    }
+    serde_json::from_value(data).map_err(batch_error)
+}
+
+fn batch_error(e: serde_json::Error) -> Auth0Error {
+    Auth0Error::Decode(format!("invalid log stream batch: {e}"))
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogEventType;
+
+    #[test]
+    fn test_receive_json_array() {
+        let receiver = LogStreamReceiver::new("Bearer secret");
+        let body = br#"[
+            {"log_id": "900400", "data": {"date": "2025-08-02T12:00:00.000Z", "type": "fp", "ip": "203.0.113.7"}},
+            {"log_id": "900401", "data": {"date": "2025-08-02T12:00:01.000Z", "type": "s", "log_id": "900401"}}
+        ]"#;
+
+        let events = receiver.receive(Some("Bearer secret"), body).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].log_id, "900400");
+        assert_eq!(
+            events[0].event_type,
+            LogEventType::FailedLoginIncorrectPassword
+        );
+        assert_eq!(events[1].event_type, LogEventType::SuccessLogin);
+    }
+
+    #[test]
+    fn test_parse_json_lines() {
+        let body = concat!(
+            r#"{"log_id": "900500", "data": {"date": "2025-08-02T12:00:00.000Z", "type": "limit_wc"}}"#,
+            "\n",
+            r#"{"log_id": "900501", "data": {"date": "2025-08-02T12:00:01.000Z", "type": "sapi"}}"#,
+            "\n",
+        );
+
+        let events = parse_log_stream_batch(body.as_bytes()).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].event_type, LogEventType::SuccessApiOperation);
+    }
+
+    #[test]
+    fn test_receive_rejects_wrong_authorization() {
+        let receiver = LogStreamReceiver::new("Bearer secret");
+
+        assert!(matches!(
+            receiver.receive(Some("Bearer secreT"), b"[]"),
+            Err(Auth0Error::Unauthorized(_))
+        ));
+        assert!(matches!(
+            receiver.receive(None, b"[]"),
+            Err(Auth0Error::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_batch() {
+        assert!(matches!(
+            parse_log_stream_batch(b"{not json"),
+            Err(Auth0Error::Decode(_))
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+//! Log functionality for Auth0
+//!
+//! This module provides functions for searching, reading and tailing the tenant logs
+//! through the Auth0 Management API v2, with typed log event types.
+
+mod get_log;
+mod log_event;
+mod search_logs;
+mod tail_logs;
+
+pub use get_log::get_log;
+pub use log_event::{LogEvent, LogEventCategory, LogEventType, LogLocationInfo};
+pub use search_logs::{search_logs, SearchLogsQuery};
+pub use tail_logs::{tail_logs, TailLogsOptions};
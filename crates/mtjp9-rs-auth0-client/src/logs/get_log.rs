@@ -0,0 +1,114 @@
+//! Log event retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_log` function for fetching a single log event. It wraps
+//! the GET /api/v2/logs/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/logs/get-logs-by-id)
+//! for more details about the get log endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::log_event::LogEvent;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches a log event by its ID.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:logs` scope
+/// * `log_id` - The ID of the log event
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The log ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_log(domain: &Domain, token: &BearerToken, log_id: &str) -> Result<LogEvent> {
+    // Validate log ID
+    if log_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Log ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and log ID
+    let endpoint = domain.to_url(&format!("/api/v2/logs/{log_id}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the log event
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response.json::<LogEvent>().await.map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::{LogEventCategory, LogEventType};
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_log_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/logs/900300")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "log_id": "900300",
+                    "date": "2025-08-02T11:00:00.000Z",
+                    "type": "limit_wc",
+                    "user_id": "auth0|123",
+                    "details": {"error": {"message": "Too many failed login attempts"}}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_log(&domain, &token, "900300").await;
+        mock.assert_async().await;
+
+        let event = result.unwrap();
+        assert_eq!(event.event_type, LogEventType::BlockedAccount);
+        assert_eq!(event.event_type.category(), LogEventCategory::RateLimit);
+        assert!(event.details.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_log_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_log(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
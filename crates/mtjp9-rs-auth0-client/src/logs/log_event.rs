@@ -0,0 +1,510 @@
+//! Log event model for Auth0 Management API v2
+//!
+//! This module provides `LogEvent`, the entry returned by the logs endpoints, and
+//! `LogEventType`, which maps the short event type codes Auth0 uses (e.g. "fp") to
+//! meaningful names and categories.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 log event type codes](https://auth0.com/docs/deploy-monitor/logs/log-event-type-codes)
+//! for the full list of event types.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Broad category of a log event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogEventCategory {
+    /// A user logged in
+    SuccessLogin,
+
+    /// A login attempt failed
+    FailedLogin,
+
+    /// A user signed up, or a sign up failed
+    Signup,
+
+    /// A user logged out, or a logout failed
+    Logout,
+
+    /// A token was issued or refused by the token endpoint
+    TokenExchange,
+
+    /// A password was changed or a change was requested
+    Password,
+
+    /// An email address was verified or a verification was requested
+    EmailVerification,
+
+    /// A multi-factor authentication attempt
+    Mfa,
+
+    /// A Management API operation
+    ApiOperation,
+
+    /// A user, IP address or client was blocked or rate limited
+    RateLimit,
+
+    /// An event with security relevance that fits no other category, such as a breached
+    /// password
+    Security,
+
+    /// Any other event, including unknown event types
+    Other,
+}
+
+/// Type of a log event
+///
+/// Event types are sent as short codes. Codes not modelled here are kept in `Other`, so
+/// new event types introduced by Auth0 do not break deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LogEventType {
+    /// `s`: Successful login
+    SuccessLogin,
+    /// `f`: Failed login
+    FailedLogin,
+    /// `fp`: Failed login due to an incorrect password
+    FailedLoginIncorrectPassword,
+    /// `fu`: Failed login due to an invalid email or username
+    FailedLoginInvalidUsername,
+    /// `scoa`: Successful cross-origin authentication
+    SuccessCrossOriginAuthentication,
+    /// `fcoa`: Failed cross-origin authentication
+    FailedCrossOriginAuthentication,
+    /// `ss`: Successful signup
+    SuccessSignup,
+    /// `fs`: Failed signup
+    FailedSignup,
+    /// `slo`: Successful logout
+    SuccessLogout,
+    /// `flo`: Failed logout
+    FailedLogout,
+    /// `seacft`: Successful exchange of an authorization code for an access token
+    SuccessExchangeAuthorizationCode,
+    /// `feacft`: Failed exchange of an authorization code for an access token
+    FailedExchangeAuthorizationCode,
+    /// `seccft`: Successful exchange of client credentials for an access token
+    SuccessExchangeClientCredentials,
+    /// `feccft`: Failed exchange of client credentials for an access token
+    FailedExchangeClientCredentials,
+    /// `sepft`: Successful exchange of a password for an access token
+    SuccessExchangePassword,
+    /// `fepft`: Failed exchange of a password for an access token
+    FailedExchangePassword,
+    /// `sertft`: Successful exchange of a refresh token for an access token
+    SuccessExchangeRefreshToken,
+    /// `fertft`: Failed exchange of a refresh token for an access token
+    FailedExchangeRefreshToken,
+    /// `scp`: Successful password change
+    SuccessChangePassword,
+    /// `fcp`: Failed password change
+    FailedChangePassword,
+    /// `scpr`: Successful password change request
+    SuccessChangePasswordRequest,
+    /// `fcpr`: Failed password change request
+    FailedChangePasswordRequest,
+    /// `sv`: Successful email verification
+    SuccessVerificationEmail,
+    /// `fv`: Failed email verification
+    FailedVerificationEmail,
+    /// `svr`: Successful verification email request
+    SuccessVerificationEmailRequest,
+    /// `fvr`: Failed verification email request
+    FailedVerificationEmailRequest,
+    /// `gd_auth_succeed`: Successful multi-factor authentication
+    MfaSuccess,
+    /// `gd_auth_failed`: Failed multi-factor authentication
+    MfaFailed,
+    /// `sapi`: Successful Management API operation
+    SuccessApiOperation,
+    /// `fapi`: Failed Management API operation
+    FailedApiOperation,
+    /// `limit_wc`: Account blocked after too many failed logins
+    BlockedAccount,
+    /// `limit_mu`: IP address blocked after failed logins for multiple users
+    BlockedIpAddress,
+    /// `limit_sul`: User blocked after too many logins from the same IP address
+    BlockedUserLoginRate,
+    /// `ublkdu`: Login block released for a user
+    UserBlockReleased,
+    /// `api_limit`: Authentication or Management API rate limit exceeded
+    ApiRateLimit,
+    /// `pwd_leak`: Login attempt with a breached password
+    BreachedPassword,
+    /// `du`: User deleted
+    DeletedUser,
+    /// `w`: Warning during login
+    Warning,
+    /// `depnote`: Deprecation notice
+    DeprecationNotice,
+    /// Any other event type code
+    Other(String),
+}
+
+impl LogEventType {
+    /// Parses an event type code. Unknown codes map to `Other`.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "s" => Self::SuccessLogin,
+            "f" => Self::FailedLogin,
+            "fp" => Self::FailedLoginIncorrectPassword,
+            "fu" => Self::FailedLoginInvalidUsername,
+            "scoa" => Self::SuccessCrossOriginAuthentication,
+            "fcoa" => Self::FailedCrossOriginAuthentication,
+            "ss" => Self::SuccessSignup,
+            "fs" => Self::FailedSignup,
+            "slo" => Self::SuccessLogout,
+            "flo" => Self::FailedLogout,
+            "seacft" => Self::SuccessExchangeAuthorizationCode,
+            "feacft" => Self::FailedExchangeAuthorizationCode,
+            "seccft" => Self::SuccessExchangeClientCredentials,
+            "feccft" => Self::FailedExchangeClientCredentials,
+            "sepft" => Self::SuccessExchangePassword,
+            "fepft" => Self::FailedExchangePassword,
+            "sertft" => Self::SuccessExchangeRefreshToken,
+            "fertft" => Self::FailedExchangeRefreshToken,
+            "scp" => Self::SuccessChangePassword,
+            "fcp" => Self::FailedChangePassword,
+            "scpr" => Self::SuccessChangePasswordRequest,
+            "fcpr" => Self::FailedChangePasswordRequest,
+            "sv" => Self::SuccessVerificationEmail,
+            "fv" => Self::FailedVerificationEmail,
+            "svr" => Self::SuccessVerificationEmailRequest,
+            "fvr" => Self::FailedVerificationEmailRequest,
+            "gd_auth_succeed" => Self::MfaSuccess,
+            "gd_auth_failed" => Self::MfaFailed,
+            "sapi" => Self::SuccessApiOperation,
+            "fapi" => Self::FailedApiOperation,
+            "limit_wc" => Self::BlockedAccount,
+            "limit_mu" => Self::BlockedIpAddress,
+            "limit_sul" => Self::BlockedUserLoginRate,
+            "ublkdu" => Self::UserBlockReleased,
+            "api_limit" => Self::ApiRateLimit,
+            "pwd_leak" => Self::BreachedPassword,
+            "du" => Self::DeletedUser,
+            "w" => Self::Warning,
+            "depnote" => Self::DeprecationNotice,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    /// The code Auth0 uses for this event type.
+    pub fn code(&self) -> &str {
+        self.info().0
+    }
+
+    /// A human readable name of the event type (e.g. "Failed Login (Incorrect Password)").
+    pub fn name(&self) -> &'static str {
+        self.info().1
+    }
+
+    /// The broad category of the event type.
+    pub fn category(&self) -> LogEventCategory {
+        self.info().2
+    }
+
+    /// Returns true for events that indicate a failed operation.
+    pub fn is_failure(&self) -> bool {
+        self.category() == LogEventCategory::FailedLogin
+            || matches!(
+                self,
+                Self::FailedCrossOriginAuthentication
+                    | Self::FailedSignup
+                    | Self::FailedLogout
+                    | Self::FailedExchangeAuthorizationCode
+                    | Self::FailedExchangeClientCredentials
+                    | Self::FailedExchangePassword
+                    | Self::FailedExchangeRefreshToken
+                    | Self::FailedChangePassword
+                    | Self::FailedChangePasswordRequest
+                    | Self::FailedVerificationEmail
+                    | Self::FailedVerificationEmailRequest
+                    | Self::MfaFailed
+                    | Self::FailedApiOperation
+            )
+    }
+
+    fn info(&self) -> (&str, &'static str, LogEventCategory) {
+        use LogEventCategory as C;
+        match self {
+            Self::SuccessLogin => ("s", "Success Login", C::SuccessLogin),
+            Self::FailedLogin => ("f", "Failed Login", C::FailedLogin),
+            Self::FailedLoginIncorrectPassword => {
+                ("fp", "Failed Login (Incorrect Password)", C::FailedLogin)
+            }
+            Self::FailedLoginInvalidUsername => (
+                "fu",
+                "Failed Login (Invalid Email/Username)",
+                C::FailedLogin,
+            ),
+            Self::SuccessCrossOriginAuthentication => (
+                "scoa",
+                "Success Cross-Origin Authentication",
+                C::SuccessLogin,
+            ),
+            Self::FailedCrossOriginAuthentication => {
+                ("fcoa", "Failed Cross-Origin Authentication", C::FailedLogin)
+            }
+            Self::SuccessSignup => ("ss", "Success Signup", C::Signup),
+            Self::FailedSignup => ("fs", "Failed Signup", C::Signup),
+            Self::SuccessLogout => ("slo", "Success Logout", C::Logout),
+            Self::FailedLogout => ("flo", "Failed Logout", C::Logout),
+            Self::SuccessExchangeAuthorizationCode => (
+                "seacft",
+                "Success Exchange (Authorization Code for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::FailedExchangeAuthorizationCode => (
+                "feacft",
+                "Failed Exchange (Authorization Code for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::SuccessExchangeClientCredentials => (
+                "seccft",
+                "Success Exchange (Client Credentials for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::FailedExchangeClientCredentials => (
+                "feccft",
+                "Failed Exchange (Client Credentials for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::SuccessExchangePassword => (
+                "sepft",
+                "Success Exchange (Password for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::FailedExchangePassword => (
+                "fepft",
+                "Failed Exchange (Password for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::SuccessExchangeRefreshToken => (
+                "sertft",
+                "Success Exchange (Refresh Token for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::FailedExchangeRefreshToken => (
+                "fertft",
+                "Failed Exchange (Refresh Token for Access Token)",
+                C::TokenExchange,
+            ),
+            Self::SuccessChangePassword => ("scp", "Success Change Password", C::Password),
+            Self::FailedChangePassword => ("fcp", "Failed Change Password", C::Password),
+            Self::SuccessChangePasswordRequest => {
+                ("scpr", "Success Change Password Request", C::Password)
+            }
+            Self::FailedChangePasswordRequest => {
+                ("fcpr", "Failed Change Password Request", C::Password)
+            }
+            Self::SuccessVerificationEmail => {
+                ("sv", "Success Verification Email", C::EmailVerification)
+            }
+            Self::FailedVerificationEmail => {
+                ("fv", "Failed Verification Email", C::EmailVerification)
+            }
+            Self::SuccessVerificationEmailRequest => (
+                "svr",
+                "Success Verification Email Request",
+                C::EmailVerification,
+            ),
+            Self::FailedVerificationEmailRequest => (
+                "fvr",
+                "Failed Verification Email Request",
+                C::EmailVerification,
+            ),
+            Self::MfaSuccess => ("gd_auth_succeed", "MFA Authentication Success", C::Mfa),
+            Self::MfaFailed => ("gd_auth_failed", "MFA Authentication Failed", C::Mfa),
+            Self::SuccessApiOperation => ("sapi", "Success API Operation", C::ApiOperation),
+            Self::FailedApiOperation => ("fapi", "Failed API Operation", C::ApiOperation),
+            Self::BlockedAccount => ("limit_wc", "Blocked Account", C::RateLimit),
+            Self::BlockedIpAddress => ("limit_mu", "Blocked IP Address", C::RateLimit),
+            Self::BlockedUserLoginRate => ("limit_sul", "Blocked User", C::RateLimit),
+            Self::UserBlockReleased => ("ublkdu", "User Login Block Released", C::RateLimit),
+            Self::ApiRateLimit => ("api_limit", "Too Many Calls to API", C::RateLimit),
+            Self::BreachedPassword => ("pwd_leak", "Breached Password", C::Security),
+            Self::DeletedUser => ("du", "Deleted User", C::Other),
+            Self::Warning => ("w", "Warnings During Login", C::Other),
+            Self::DeprecationNotice => ("depnote", "Deprecation Notice", C::Other),
+            Self::Other(code) => (code.as_str(), "Unknown Event", C::Other),
+        }
+    }
+}
+
+impl From<String> for LogEventType {
+    fn from(code: String) -> Self {
+        match Self::from_code(&code) {
+            Self::Other(_) => Self::Other(code),
+            known => known,
+        }
+    }
+}
+
+impl From<LogEventType> for String {
+    fn from(event_type: LogEventType) -> Self {
+        match event_type {
+            LogEventType::Other(code) => code,
+            known => known.code().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LogEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Geographic information derived from the IP address of a log event
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LogLocationInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+}
+
+/// An entry of the tenant logs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEvent {
+    /// The log entry's unique identifier, usable as a `from` checkpoint
+    pub log_id: String,
+
+    /// When the event occurred
+    pub date: String,
+
+    /// Type of the event
+    #[serde(rename = "type")]
+    pub event_type: LogEventType,
+
+    /// Description of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Name of the connection the event relates to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+
+    /// ID of the connection the event relates to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+
+    /// ID of the application the event relates to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Name of the application the event relates to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_name: Option<String>,
+
+    /// IP address the event originated from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// Hostname the event applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+
+    /// ID of the user involved in the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+
+    /// Name of the user involved in the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+
+    /// User agent string of the client that caused the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// API audience the event applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Scope permissions applied to the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Value>,
+
+    /// Name of the connection strategy (e.g. "auth0")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+
+    /// Type of the connection strategy (e.g. "database")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<String>,
+
+    /// Whether the client was a mobile device
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mobile: Option<bool>,
+
+    /// Additional, event specific details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
+
+    /// Location derived from the IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_info: Option<LogLocationInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_event_type_codes_round_trip() {
+        for code in [
+            "s",
+            "fp",
+            "fu",
+            "seacft",
+            "limit_wc",
+            "sapi",
+            "gd_auth_failed",
+        ] {
+            let event_type = LogEventType::from_code(code);
+            assert!(!matches!(event_type, LogEventType::Other(_)), "{code}");
+            assert_eq!(event_type.code(), code);
+        }
+
+        let unknown: LogEventType = serde_json::from_str(r#""new_code""#).unwrap();
+        assert_eq!(unknown, LogEventType::Other("new_code".to_string()));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""new_code""#);
+    }
+
+    #[test]
+    fn test_log_event_type_categories() {
+        assert_eq!(
+            LogEventType::FailedLoginIncorrectPassword.category(),
+            LogEventCategory::FailedLogin
+        );
+        assert_eq!(
+            LogEventType::SuccessExchangeAuthorizationCode.category(),
+            LogEventCategory::TokenExchange
+        );
+        assert_eq!(
+            LogEventType::BlockedAccount.category(),
+            LogEventCategory::RateLimit
+        );
+        assert!(LogEventType::FailedApiOperation.is_failure());
+        assert!(!LogEventType::SuccessLogin.is_failure());
+        assert_eq!(
+            LogEventType::FailedLoginInvalidUsername.name(),
+            "Failed Login (Invalid Email/Username)"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Log search for Auth0 Management API v2
+//!
+//! This module provides the `search_logs` function for searching the tenant logs. It wraps
+//! the GET /api/v2/logs endpoint, which supports two kinds of pagination:
+//!
+//! * Search: a Lucene query in `q` with `page`, `per_page` and `sort`
+//! * Checkpoint: `from` a log ID, returning the next `take` events in order
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     logs::{LogEventType, SearchLogsQuery, search_logs},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Failed logins of the first week of August
+//!     let query = SearchLogsQuery::default()
+//!         .with_event_types(&[
+//!             LogEventType::FailedLogin,
+//!             LogEventType::FailedLoginIncorrectPassword,
+//!             LogEventType::FailedLoginInvalidUsername,
+//!         ])
+//!         .with_date_range(Some("2025-08-01"), Some("2025-08-07"));
+//!
+//!     for event in search_logs(&domain, &token, query).await? {
+//!         println!("{} {} {:?}", event.date, event.event_type.name(), event.ip);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/logs/get-logs)
+//! for more details about the search logs endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::log_event::{LogEvent, LogEventType};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for searching logs
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SearchLogsQuery {
+    /// Lucene query string (e.g. `type:"fp" AND user_id:"auth0|123"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+
+    /// Log ID to start after, for checkpoint pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Number of events to return with checkpoint pagination (at most 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take: Option<u32>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page (at most 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+
+    /// Field to sort by, with 1 for ascending and -1 for descending order (e.g. "date:-1")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+
+    /// Comma-separated list of fields to include or exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+
+    /// Whether the specified fields are included (true) or excluded (false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+impl SearchLogsQuery {
+    /// Starts checkpoint pagination after the given log ID.
+    pub fn from_checkpoint(log_id: impl Into<String>, take: u32) -> Self {
+        Self {
+            from: Some(log_id.into()),
+            take: Some(take),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the search to events with a date in the given range. Dates are inclusive
+    /// and use ISO 8601 (e.g. "2025-08-01" or "2025-08-01T12:00:00Z"); `None` leaves that
+    /// end of the range open.
+    pub fn with_date_range(self, from: Option<&str>, to: Option<&str>) -> Self {
+        let clause = format!("date:[{} TO {}]", from.unwrap_or("*"), to.unwrap_or("*"));
+        self.and_query(clause)
+    }
+
+    /// Restricts the search to events of the given types.
+    pub fn with_event_types(self, event_types: &[LogEventType]) -> Self {
+        if event_types.is_empty() {
+            return self;
+        }
+        let codes = event_types
+            .iter()
+            .map(|event_type| format!("\"{}\"", event_type.code()))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        self.and_query(format!("type:({codes})"))
+    }
+
+    fn and_query(mut self, clause: String) -> Self {
+        self.q = Some(match self.q.take() {
+            Some(q) if !q.is_empty() => format!("({q}) AND {clause}"),
+            _ => clause,
+        });
+        self
+    }
+}
+
+/// Searches the tenant logs.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:logs` scope
+/// * `query` - Search or checkpoint pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * `from` is combined with `q`, `page`, `per_page` or `sort`, which Auth0 ignores with
+///   checkpoint pagination
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+///
+/// # Rate Limiting
+///
+/// Search pagination only reaches the first 1000 results of a query. Use checkpoint
+/// pagination to read large volumes of logs.
+pub async fn search_logs(
+    domain: &Domain,
+    token: &BearerToken,
+    query: SearchLogsQuery,
+) -> Result<Vec<LogEvent>> {
+    // Validate that checkpoint and search pagination are not mixed
+    if query.from.is_some()
+        && (query.q.is_some()
+            || query.page.is_some()
+            || query.per_page.is_some()
+            || query.sort.is_some())
+    {
+        return Err(Auth0Error::InvalidRequest(
+            "Checkpoint pagination with from cannot be combined with q, page, per_page or sort"
+                .to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/logs");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to search the logs
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<LogEvent>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::LogEventCategory;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_search_logs_failed_logins() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let query = SearchLogsQuery {
+            q: Some("connection:\"customers-db\"".to_string()),
+            per_page: Some(50),
+            sort: Some("date:-1".to_string()),
+            ..Default::default()
+        }
+        .with_event_types(&[LogEventType::FailedLoginIncorrectPassword])
+        .with_date_range(Some("2025-08-01"), None);
+
+        let mock = server
+            .mock("GET", "/api/v2/logs")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "q".into(),
+                    r#"((connection:"customers-db") AND type:("fp")) AND date:[2025-08-01 TO *]"#
+                        .into(),
+                ),
+                Matcher::UrlEncoded("per_page".into(), "50".into()),
+                Matcher::UrlEncoded("sort".into(), "date:-1".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{
+                    "log_id": "900200",
+                    "date": "2025-08-02T10:00:00.000Z",
+                    "type": "fp",
+                    "description": "Wrong email or password.",
+                    "connection": "customers-db",
+                    "ip": "203.0.113.7",
+                    "user_name": "jane@example.com",
+                    "location_info": {"country_code": "NL", "city_name": "Amsterdam"}
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = search_logs(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let events = result.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].event_type,
+            LogEventType::FailedLoginIncorrectPassword
+        );
+        assert_eq!(
+            events[0].event_type.category(),
+            LogEventCategory::FailedLogin
+        );
+        assert_eq!(
+            events[0]
+                .location_info
+                .as_ref()
+                .unwrap()
+                .country_code
+                .as_deref(),
+            Some("NL")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_logs_checkpoint() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("from".into(), "900200".into()),
+                Matcher::UrlEncoded("take".into(), "100".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{"log_id": "900201", "date": "2025-08-02T10:00:01.000Z", "type": "new_event_type"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = search_logs(
+            &domain,
+            &token,
+            SearchLogsQuery::from_checkpoint("900200", 100),
+        )
+        .await;
+        mock.assert_async().await;
+
+        let events = result.unwrap();
+        assert_eq!(
+            events[0].event_type,
+            LogEventType::Other("new_event_type".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_logs_mixed_pagination() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let query = SearchLogsQuery::from_checkpoint("900200", 100)
+            .with_event_types(&[LogEventType::FailedLogin]);
+
+        let result = search_logs(&domain, &token, query).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,378 @@
+//! Continuous log tailing for Auth0 Management API v2
+//!
+//! This module provides `tail_logs`, which follows the tenant logs as a `Stream` of log
+//! events. It polls the GET /api/v2/logs endpoint with checkpoint pagination, so no event
+//! is skipped or delivered twice while the stream runs.
+//!
+//! Every event carries its `log_id`. Storing the ID of the last processed event and passing
+//! it to `tail_logs` after a restart resumes the stream exactly where it stopped.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::TryStreamExt;
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     logs::{LogEventCategory, TailLogsOptions, tail_logs},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, fs};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Resume from the last checkpoint, if any
+//!     let checkpoint = fs::read_to_string("auth0-logs.checkpoint").ok();
+//!     let events = tail_logs(&domain, &token, checkpoint.as_deref(), TailLogsOptions::default())?;
+//!
+//!     let mut events = Box::pin(events);
+//!     while let Some(event) = events.try_next().await? {
+//!         if event.event_type.category() == LogEventCategory::FailedLogin {
+//!             println!("{} {:?} {:?}", event.date, event.user_name, event.ip);
+//!         }
+//!         let _ = fs::write("auth0-logs.checkpoint", &event.log_id);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 log retrieval documentation](https://auth0.com/docs/deploy-monitor/logs/retrieve-log-events-using-mgmt-api)
+//! for more details about checkpoint pagination.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::log_event::LogEvent;
+use super::search_logs::{search_logs, SearchLogsQuery};
+
+/// Maximum number of events Auth0 returns per checkpoint request
+const MAX_TAKE: u32 = 100;
+
+/// Options for tailing logs
+#[derive(Debug, Clone)]
+pub struct TailLogsOptions {
+    /// Number of events to fetch per request (1 to 100)
+    pub take: u32,
+
+    /// How long to wait before polling again once all available events were delivered
+    pub poll_interval: Duration,
+
+    /// Upper bound of the delay between polls while rate limited
+    pub max_backoff: Duration,
+}
+
+impl Default for TailLogsOptions {
+    fn default() -> Self {
+        Self {
+            take: MAX_TAKE,
+            poll_interval: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+struct TailState {
+    domain: Domain,
+    token: BearerToken,
+    options: TailLogsOptions,
+    checkpoint: Option<String>,
+    events: VecDeque<LogEvent>,
+    delay: Option<Duration>,
+}
+
+impl TailState {
+    /// Fetches the events after the checkpoint. Without a checkpoint, the newest event
+    /// becomes the checkpoint, so tailing starts at the current end of the logs; if there
+    /// are no logs yet, the lookup is repeated after `poll_interval`.
+    async fn poll(&mut self) -> Result<()> {
+        let Some(checkpoint) = &self.checkpoint else {
+            let query = SearchLogsQuery {
+                sort: Some("date:-1".to_string()),
+                per_page: Some(1),
+                ..Default::default()
+            };
+            let newest = search_logs(&self.domain, &self.token, query).await?;
+            self.checkpoint = newest.into_iter().next().map(|event| event.log_id);
+            self.delay = Some(self.options.poll_interval);
+            return Ok(());
+        };
+
+        let query = SearchLogsQuery::from_checkpoint(checkpoint.clone(), self.options.take);
+        let events = search_logs(&self.domain, &self.token, query).await?;
+        if let Some(last) = events.last() {
+            self.checkpoint = Some(last.log_id.clone());
+        }
+
+        // Keep polling without a pause while catching up on a backlog
+        self.delay = if events.len() >= self.options.take as usize {
+            None
+        } else {
+            Some(self.options.poll_interval)
+        };
+        self.events.extend(events);
+        Ok(())
+    }
+}
+
+/// Follows the tenant logs, yielding every new log event in order.
+///
+/// The stream never ends on its own. Once all available events were delivered, it polls
+/// again after `poll_interval`.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API requests
+/// * `token` - A valid Management API access token with the `read:logs` scope
+/// * `from_log_id` - The `log_id` of the last processed event, or `None` to start with
+///   the events logged from now on
+/// * `options` - Page size and polling intervals
+///
+/// # Errors
+///
+/// Returns an `Auth0Error::InvalidRequest` if `take` is not between 1 and 100. Errors of
+/// the log requests are yielded by the stream, which then ends; it can be restarted from
+/// the last delivered `log_id`.
+///
+/// # Rate Limiting
+///
+/// Requests answered with 429 Too Many Requests are retried after a delay that doubles
+/// with every rate limited request, up to `max_backoff`.
+pub fn tail_logs(
+    domain: &Domain,
+    token: &BearerToken,
+    from_log_id: Option<&str>,
+    options: TailLogsOptions,
+) -> Result<impl Stream<Item = Result<LogEvent>>> {
+    // Validate page size
+    if options.take == 0 || options.take > MAX_TAKE {
+        return Err(Auth0Error::InvalidRequest(format!(
+            "take must be between 1 and {MAX_TAKE}"
+        )));
+    }
+
+    let state = TailState {
+        domain: domain.clone(),
+        token: token.clone(),
+        options,
+        checkpoint: from_log_id.filter(|id| !id.is_empty()).map(str::to_string),
+        events: VecDeque::new(),
+        delay: None,
+    };
+
+    Ok(stream::try_unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.events.pop_front() {
+                return Ok(Some((event, state)));
+            }
+            if let Some(delay) = state.delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            match state.poll().await {
+                Ok(()) => {}
+                Err(Auth0Error::TooManyRequests(_)) => {
+                    let delay = state.delay.unwrap_or(state.options.poll_interval);
+                    state.delay = Some(delay.saturating_mul(2).min(state.options.max_backoff));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use futures::{StreamExt, TryStreamExt};
+    use mockito::{Matcher, Server};
+
+    fn test_options() -> TailLogsOptions {
+        TailLogsOptions {
+            take: 2,
+            poll_interval: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(40),
+        }
+    }
+
+    fn checkpoint_query(from: &str) -> Matcher {
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("from".into(), from.into()),
+            Matcher::UrlEncoded("take".into(), "2".into()),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_starts_at_newest_event() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let newest = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sort".into(), "date:-1".into()),
+                Matcher::UrlEncoded("per_page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[{"log_id": "100", "date": "2025-08-02T10:00:00.000Z", "type": "s"}]"#)
+            .create_async()
+            .await;
+        let _empty = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("100"))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+        let _next = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("100"))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[{"log_id": "101", "date": "2025-08-02T10:00:01.000Z", "type": "fp"}]"#)
+            .create_async()
+            .await;
+
+        let events = tail_logs(&domain, &token, None, test_options()).unwrap();
+        let events: Vec<LogEvent> = events.take(1).try_collect().await.unwrap();
+        newest.assert_async().await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].log_id, "101");
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_waits_while_tenant_has_no_logs() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let newest = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sort".into(), "date:-1".into()),
+                Matcher::UrlEncoded("per_page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let options = TailLogsOptions {
+            poll_interval: Duration::from_secs(1),
+            ..test_options()
+        };
+        let events = tail_logs(&domain, &token, None, options).unwrap();
+        let mut events = Box::pin(events);
+        // The first lookup is immediate; the next one must wait for `poll_interval`
+        let next = tokio::time::timeout(Duration::from_millis(500), events.next()).await;
+        newest.assert_async().await;
+
+        assert!(next.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_resumes_and_backs_off() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let page = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("200"))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"log_id": "201", "date": "2025-08-02T10:00:01.000Z", "type": "f"},
+                    {"log_id": "202", "date": "2025-08-02T10:00:02.000Z", "type": "sapi"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+        let rate_limited = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("202"))
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let _next = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("202"))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{"log_id": "203", "date": "2025-08-02T10:00:03.000Z", "type": "limit_wc"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let events = tail_logs(&domain, &token, Some("200"), test_options()).unwrap();
+        let ids: Vec<String> = events
+            .map_ok(|event| event.log_id)
+            .take(3)
+            .try_collect()
+            .await
+            .unwrap();
+        page.assert_async().await;
+        rate_limited.assert_async().await;
+
+        assert_eq!(ids, vec!["201", "202", "203"]);
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_ends_on_error() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _mock = server
+            .mock("GET", "/api/v2/logs")
+            .match_query(checkpoint_query("300"))
+            .with_status(403)
+            .with_body(r#"{"message": "Insufficient scope"}"#)
+            .create_async()
+            .await;
+
+        let events = tail_logs(&domain, &token, Some("300"), test_options()).unwrap();
+        let results: Vec<Result<LogEvent>> = events.collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(Auth0Error::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_tail_logs_invalid_take() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let options = TailLogsOptions {
+            take: 101,
+            ..Default::default()
+        };
+        assert!(matches!(
+            tail_logs(&domain, &token, None, options),
+            Err(Auth0Error::InvalidRequest(_))
+        ));
+    }
+}
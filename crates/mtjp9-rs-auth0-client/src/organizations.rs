@@ -3,12 +3,30 @@
 //! This module provides functions for managing organizations through the Auth0 Management API v2.
 
 mod create_organization;
+mod enabled_connections;
+mod ensure_organization;
+mod get_client_grants;
+mod get_organization_by_name;
 mod patch_organization;
+mod post_client_grants;
 mod post_members;
+mod remove_organization_client_grant;
 
 pub use create_organization::{
     create_organization, BrandingColors, CreateOrganizationRequest, EnabledConnection,
     OrganizationBranding, OrganizationResponse,
 };
+pub use enabled_connections::{
+    add_enabled_connection, list_enabled_connections, remove_enabled_connection,
+    update_enabled_connection,
+};
+pub use ensure_organization::{
+    diff_enabled_connections, diff_organization, ensure_organization, EnabledConnectionsDiff,
+    EnsureOrganizationOutcome,
+};
+pub use get_client_grants::{get_client_grants, GetClientGrantsQuery};
+pub use get_organization_by_name::get_organization_by_name;
 pub use patch_organization::{patch_organization, PatchOrganizationRequest};
+pub use post_client_grants::{post_client_grants, AssociateClientGrantRequest};
 pub use post_members::{post_members, AddMembersRequest};
+pub use remove_organization_client_grant::remove_organization_client_grant;
@@ -77,7 +77,7 @@ pub struct CreateOrganizationRequest {
 }
 
 /// Branding configuration for an organization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrganizationBranding {
     /// URL for the organization's logo
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,7 +89,7 @@ pub struct OrganizationBranding {
 }
 
 /// Color configuration for organization branding
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BrandingColors {
     /// Primary color (hex format, e.g., "#FF5733")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,7 +101,7 @@ pub struct BrandingColors {
 }
 
 /// Enabled connection configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnabledConnection {
     /// The connection ID
     pub connection_id: String,
@@ -0,0 +1,478 @@
+//! Organization enabled connections for Auth0 Management API v2
+//!
+//! This module provides functions for managing the connections members of an organization
+//! can log in with. It wraps the /api/v2/organizations/{id}/enabled_connections endpoints.
+//!
+//! The organization endpoints do not return enabled connections, so these functions are
+//! also what `ensure_organization` uses to reconcile them.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     organizations::{EnabledConnection, add_enabled_connection, list_enabled_connections},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let connection = EnabledConnection {
+//!         connection_id: "con_abc".to_string(),
+//!         assign_membership_on_login: true,
+//!         show_as_button: true,
+//!         is_signup_enabled: false,
+//!     };
+//!     add_enabled_connection(&domain, &token, "org_123", &connection).await?;
+//!
+//!     for connection in list_enabled_connections(&domain, &token, "org_123").await? {
+//!         println!("{} is enabled", connection.connection_id);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/organizations/get-enabled-connections)
+//! for more details about the enabled connections endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::create_organization::EnabledConnection;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Largest page size accepted by the API
+const PER_PAGE: usize = 100;
+
+/// Request body for updating an enabled connection; the connection is named in the path
+#[derive(Serialize)]
+struct UpdateEnabledConnectionRequest {
+    assign_membership_on_login: bool,
+    show_as_button: bool,
+    is_signup_enabled: bool,
+}
+
+/// Lists all connections enabled for an organization.
+///
+/// Every page of results is fetched.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:organization_connections` scope
+/// * `organization_id` - The ID of the organization
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_enabled_connections(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+) -> Result<Vec<EnabledConnection>> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and organization ID
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/enabled_connections"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    let mut connections = Vec::new();
+    for page in 0.. {
+        // Send the GET request for the next page of enabled connections
+        let response = client
+            .get(&endpoint)
+            .bearer_auth(token.as_str())
+            .query(&[("page", page), ("per_page", PER_PAGE)])
+            .send()
+            .await?;
+
+        // Handle the response based on status code
+        let batch = match response.status() {
+            StatusCode::OK => response.json::<Vec<EnabledConnection>>().await?,
+            _ => return Err(Auth0Error::from_response(response).await),
+        };
+
+        let last_page = batch.len() < PER_PAGE;
+        connections.extend(batch);
+        if last_page {
+            break;
+        }
+    }
+
+    Ok(connections)
+}
+
+/// Enables a connection for an organization.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:organization_connections` scope
+/// * `organization_id` - The ID of the organization
+/// * `connection` - The connection to enable and its settings
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID or connection ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes), such as 409 Conflict
+///   when the connection is already enabled
+/// * The response cannot be deserialized
+pub async fn add_enabled_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    connection: &EnabledConnection,
+) -> Result<EnabledConnection> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate connection_id
+    if connection.connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and organization ID
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/enabled_connections"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to enable the connection
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(connection)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<EnabledConnection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Updates the settings of a connection enabled for an organization.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:organization_connections` scope
+/// * `organization_id` - The ID of the organization
+/// * `connection` - The enabled connection, identified by `connection_id`, with its new settings
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID or connection ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_enabled_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    connection: &EnabledConnection,
+) -> Result<EnabledConnection> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate connection_id
+    if connection.connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain, organization ID and connection ID
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/enabled_connections/{}",
+        connection.connection_id
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    let request = UpdateEnabledConnectionRequest {
+        assign_membership_on_login: connection.assign_membership_on_login,
+        show_as_button: connection.show_as_button,
+        is_signup_enabled: connection.is_signup_enabled,
+    };
+
+    // Send the PATCH request to update the enabled connection
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<EnabledConnection>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Disables a connection for an organization.
+///
+/// The connection itself is not deleted.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:organization_connections` scope
+/// * `organization_id` - The ID of the organization
+/// * `connection_id` - The ID of the connection to disable
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID or connection ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn remove_enabled_connection(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    connection_id: &str,
+) -> Result<()> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate connection_id
+    if connection_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Connection ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain, organization ID and connection ID
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/enabled_connections/{connection_id}"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to disable the connection
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    fn connection(connection_id: &str) -> EnabledConnection {
+        EnabledConnection {
+            connection_id: connection_id.to_string(),
+            assign_membership_on_login: true,
+            show_as_button: true,
+            is_signup_enabled: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_enabled_connections_all_pages() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let full_page: Vec<_> = (0..PER_PAGE)
+            .map(|i| connection(&format!("con_{i}")))
+            .collect();
+        let first = server
+            .mock("GET", "/api/v2/organizations/org_123/enabled_connections")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "0".into()),
+                Matcher::UrlEncoded("per_page".into(), "100".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(serde_json::to_string(&full_page).unwrap())
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/api/v2/organizations/org_123/enabled_connections")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[{
+                    "connection_id": "con_last",
+                    "assign_membership_on_login": false,
+                    "show_as_button": true,
+                    "is_signup_enabled": true,
+                    "connection": {"name": "google-oauth2", "strategy": "google-oauth2"}
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let result = list_enabled_connections(&domain, &token, "org_123").await;
+        first.assert_async().await;
+        second.assert_async().await;
+
+        let connections = result.unwrap();
+        assert_eq!(connections.len(), PER_PAGE + 1);
+        assert_eq!(connections[PER_PAGE].connection_id, "con_last");
+        assert!(connections[PER_PAGE].is_signup_enabled);
+    }
+
+    #[tokio::test]
+    async fn test_add_enabled_connection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/organizations/org_123/enabled_connections")
+            .match_body(Matcher::JsonString(
+                r#"{"connection_id": "con_a", "assign_membership_on_login": true, "show_as_button": true, "is_signup_enabled": false}"#
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"connection_id": "con_a", "assign_membership_on_login": true, "show_as_button": true}"#)
+            .create_async()
+            .await;
+
+        let result = add_enabled_connection(&domain, &token, "org_123", &connection("con_a")).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap(), connection("con_a"));
+    }
+
+    #[tokio::test]
+    async fn test_update_enabled_connection_sends_settings_only() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "PATCH",
+                "/api/v2/organizations/org_123/enabled_connections/con_a",
+            )
+            .match_body(Matcher::JsonString(
+                r#"{"assign_membership_on_login": true, "show_as_button": true, "is_signup_enabled": false}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"connection_id": "con_a", "assign_membership_on_login": true, "show_as_button": true}"#)
+            .create_async()
+            .await;
+
+        let result =
+            update_enabled_connection(&domain, &token, "org_123", &connection("con_a")).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_remove_enabled_connection_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "DELETE",
+                "/api/v2/organizations/org_123/enabled_connections/con_a",
+            )
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = remove_enabled_connection(&domain, &token, "org_123", "con_a").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_enabled_connections_empty_ids() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = list_enabled_connections(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+
+        let result = add_enabled_connection(&domain, &token, "org_123", &connection("")).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+
+        let result = remove_enabled_connection(&domain, &token, "org_123", "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,661 @@
+//! Idempotent organization provisioning for Auth0 Management API v2
+//!
+//! This module provides the `ensure_organization` function, which reconciles an organization
+//! in Auth0 with a desired configuration. The organization is looked up by `name`, created
+//! if it does not exist, and otherwise patched with only the fields that differ. Its enabled
+//! connections are reconciled through the enabled connections endpoints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     organizations::{CreateOrganizationRequest, EnsureOrganizationOutcome, ensure_organization},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let desired = CreateOrganizationRequest {
+//!         name: "acme-corp".to_string(),
+//!         display_name: Some("Acme Corporation".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     match ensure_organization(&domain, &token, desired).await? {
+//!         EnsureOrganizationOutcome::Created(org) => println!("Created {}", org.id),
+//!         EnsureOrganizationOutcome::Updated(org) => println!("Updated {}", org.id),
+//!         EnsureOrganizationOutcome::Unchanged(org) => println!("{} is up to date", org.id),
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+
+use super::create_organization::{
+    create_organization, CreateOrganizationRequest, EnabledConnection, OrganizationResponse,
+};
+use super::enabled_connections::{
+    add_enabled_connection, list_enabled_connections, remove_enabled_connection,
+    update_enabled_connection,
+};
+use super::get_organization_by_name::get_organization_by_name;
+use super::patch_organization::{patch_organization, PatchOrganizationRequest};
+
+/// The result of reconciling an organization with `ensure_organization`
+#[derive(Debug, Clone)]
+pub enum EnsureOrganizationOutcome {
+    /// The organization did not exist and was created
+    Created(OrganizationResponse),
+
+    /// The organization existed and differing fields or enabled connections were updated
+    Updated(OrganizationResponse),
+
+    /// The organization existed and already matched the desired configuration
+    Unchanged(OrganizationResponse),
+}
+
+impl EnsureOrganizationOutcome {
+    /// The organization as it exists in Auth0 after reconciliation.
+    pub fn organization(&self) -> &OrganizationResponse {
+        match self {
+            Self::Created(org) | Self::Updated(org) | Self::Unchanged(org) => org,
+        }
+    }
+}
+
+/// The changes needed to bring the enabled connections of an organization in line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnabledConnectionsDiff {
+    /// Connections to enable
+    pub add: Vec<EnabledConnection>,
+
+    /// Enabled connections whose settings differ
+    pub update: Vec<EnabledConnection>,
+
+    /// IDs of connections to disable
+    pub remove: Vec<String>,
+}
+
+impl EnabledConnectionsDiff {
+    /// Whether the enabled connections already match.
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.update.is_empty() && self.remove.is_empty()
+    }
+}
+
+/// Computes the changes needed to turn the `current` enabled connections into `desired`.
+///
+/// Connections are matched by `connection_id`; their order does not matter.
+pub fn diff_enabled_connections(
+    current: &[EnabledConnection],
+    desired: &[EnabledConnection],
+) -> EnabledConnectionsDiff {
+    let mut diff = EnabledConnectionsDiff::default();
+
+    for connection in desired {
+        match current
+            .iter()
+            .find(|c| c.connection_id == connection.connection_id)
+        {
+            None => diff.add.push(connection.clone()),
+            Some(existing) if existing != connection => diff.update.push(connection.clone()),
+            Some(_) => {}
+        }
+    }
+
+    diff.remove = current
+        .iter()
+        .filter(|c| !desired.iter().any(|d| d.connection_id == c.connection_id))
+        .map(|c| c.connection_id.clone())
+        .collect();
+
+    diff
+}
+
+/// Computes the minimal patch needed to bring `current` in line with `desired`.
+///
+/// Only fields that are set in `desired` are considered; fields left as `None` are treated
+/// as "don't care" and never reset. Enabled connections are not part of the patch; they
+/// are compared with `diff_enabled_connections`.
+///
+/// Returns `None` if no update is needed.
+pub fn diff_organization(
+    current: &OrganizationResponse,
+    desired: &CreateOrganizationRequest,
+) -> Option<PatchOrganizationRequest> {
+    let mut patch = PatchOrganizationRequest::default();
+    let mut changed = false;
+
+    if desired.display_name.is_some() && desired.display_name != current.display_name {
+        patch.display_name = desired.display_name.clone();
+        changed = true;
+    }
+
+    if desired.branding.is_some() && desired.branding != current.branding {
+        patch.branding = desired.branding.clone();
+        changed = true;
+    }
+
+    if desired.metadata.is_some() && desired.metadata != current.metadata {
+        patch.metadata = desired.metadata.clone();
+        changed = true;
+    }
+
+    changed.then_some(patch)
+}
+
+/// Ensures an organization exists in Auth0 and matches the desired configuration.
+///
+/// The organization is looked up by `desired.name`. If it does not exist it is created with
+/// `create_organization`; otherwise `diff_organization` is used to compute a minimal
+/// `PatchOrganizationRequest`, which is only sent if something changed. Running this
+/// function repeatedly with the same input is safe.
+///
+/// If `desired.enabled_connections` is set, the enabled connections of an existing
+/// organization are listed and made to match it exactly: missing connections are added,
+/// differing settings are updated and other connections are removed.
+///
+/// If the organization is created concurrently between the lookup and the create call,
+/// the resulting conflict is resolved by looking the organization up again and reconciling it.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:organizations`,
+///   `create:organizations` and `update:organizations` scopes, and the
+///   `read:organization_connections`, `create:organization_connections`,
+///   `update:organization_connections` and `delete:organization_connections` scopes to
+///   reconcile enabled connections
+/// * `desired` - The desired organization configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization name is empty
+/// * Any of the underlying API calls fail
+pub async fn ensure_organization(
+    domain: &Domain,
+    token: &BearerToken,
+    desired: CreateOrganizationRequest,
+) -> Result<EnsureOrganizationOutcome> {
+    let current = match get_organization_by_name(domain, token, &desired.name).await? {
+        Some(current) => current,
+        None => match create_organization(domain, token, desired.clone()).await {
+            Ok(created) => return Ok(EnsureOrganizationOutcome::Created(created)),
+            Err(Auth0Error::Conflict { status, body }) => {
+                get_organization_by_name(domain, token, &desired.name)
+                    .await?
+                    .ok_or(Auth0Error::Conflict { status, body })?
+            }
+            Err(e) => return Err(e),
+        },
+    };
+
+    let (mut organization, mut updated) = match diff_organization(&current, &desired) {
+        Some(patch) => (
+            patch_organization(domain, token, &current.id, patch).await?,
+            true,
+        ),
+        None => (current, false),
+    };
+
+    if let Some(connections) = desired.enabled_connections {
+        let existing = list_enabled_connections(domain, token, &organization.id).await?;
+        let diff = diff_enabled_connections(&existing, &connections);
+        updated |= !diff.is_empty();
+
+        for connection in &diff.add {
+            add_enabled_connection(domain, token, &organization.id, connection).await?;
+        }
+        for connection in &diff.update {
+            update_enabled_connection(domain, token, &organization.id, connection).await?;
+        }
+        for connection_id in &diff.remove {
+            remove_enabled_connection(domain, token, &organization.id, connection_id).await?;
+        }
+
+        organization.enabled_connections = Some(connections);
+    }
+
+    Ok(if updated {
+        EnsureOrganizationOutcome::Updated(organization)
+    } else {
+        EnsureOrganizationOutcome::Unchanged(organization)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organizations::{BrandingColors, EnabledConnection, OrganizationBranding};
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    fn current_org() -> OrganizationResponse {
+        OrganizationResponse {
+            id: "org_123456".to_string(),
+            name: "test-org".to_string(),
+            display_name: Some("Test Organization".to_string()),
+            branding: None,
+            metadata: Some(serde_json::json!({"tier": "gold"})),
+            enabled_connections: Some(vec![
+                EnabledConnection {
+                    connection_id: "con_a".to_string(),
+                    assign_membership_on_login: true,
+                    show_as_button: true,
+                    is_signup_enabled: false,
+                },
+                EnabledConnection {
+                    connection_id: "con_b".to_string(),
+                    assign_membership_on_login: false,
+                    show_as_button: true,
+                    is_signup_enabled: false,
+                },
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_diff_organization_unchanged() {
+        let current = current_org();
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("Test Organization".to_string()),
+            metadata: Some(serde_json::json!({"tier": "gold"})),
+            ..Default::default()
+        };
+
+        assert!(diff_organization(&current, &desired).is_none());
+    }
+
+    #[test]
+    fn test_diff_enabled_connections_detects_drift() {
+        let current = current_org().enabled_connections.unwrap();
+
+        // Same connections in another order
+        let reordered: Vec<_> = current.iter().rev().cloned().collect();
+        assert!(diff_enabled_connections(&current, &reordered).is_empty());
+
+        let mut changed = current[0].clone();
+        changed.is_signup_enabled = true;
+        let added = EnabledConnection {
+            connection_id: "con_c".to_string(),
+            assign_membership_on_login: false,
+            show_as_button: false,
+            is_signup_enabled: false,
+        };
+        let diff = diff_enabled_connections(&current, &[changed.clone(), added.clone()]);
+
+        assert_eq!(diff.add, vec![added]);
+        assert_eq!(diff.update, vec![changed]);
+        assert_eq!(diff.remove, vec!["con_b".to_string()]);
+    }
+
+    #[test]
+    fn test_diff_organization_only_changed_fields() {
+        let current = current_org();
+        let branding = OrganizationBranding {
+            logo_url: Some("https://example.com/logo.png".to_string()),
+            colors: Some(BrandingColors {
+                primary: Some("#FF5733".to_string()),
+                page_background: None,
+            }),
+        };
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("Test Organization".to_string()),
+            branding: Some(branding.clone()),
+            metadata: Some(serde_json::json!({"tier": "platinum"})),
+            ..Default::default()
+        };
+
+        let patch = diff_organization(&current, &desired).expect("Patch expected");
+        assert_eq!(patch.display_name, None);
+        assert_eq!(patch.name, None);
+        assert_eq!(patch.branding, Some(branding));
+        assert_eq!(
+            patch.metadata,
+            Some(serde_json::json!({"tier": "platinum"}))
+        );
+        assert!(patch.enabled_connections.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_creates_when_missing() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(404)
+            .create_async()
+            .await;
+        let create = server
+            .mock("POST", "/api/v2/organizations")
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_new", "name": "test-org"}"#)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        lookup.assert_async().await;
+        create.assert_async().await;
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Created(_)));
+        assert_eq!(outcome.organization().id, "org_new");
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_patches_differences() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "Old"}"#)
+            .create_async()
+            .await;
+        let patch = server
+            .mock("PATCH", "/api/v2/organizations/org_123456")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"display_name": "New"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "New"}"#)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("New".to_string()),
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        lookup.assert_async().await;
+        patch.assert_async().await;
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Updated(_)));
+        assert_eq!(outcome.organization().display_name, Some("New".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_unchanged_skips_patch() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "Same"}"#)
+            .create_async()
+            .await;
+        let patch = server
+            .mock("PATCH", "/api/v2/organizations/org_123456")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("Same".to_string()),
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        lookup.assert_async().await;
+        patch.assert_async().await;
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Unchanged(_)));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_reconciles_connections_of_existing() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "Same"}"#)
+            .create_async()
+            .await;
+        let patch = server
+            .mock("PATCH", "/api/v2/organizations/org_123456")
+            .expect(0)
+            .create_async()
+            .await;
+        // con_a has drifted settings, con_b is missing and con_x was enabled out of band
+        let list = server
+            .mock("GET", "/api/v2/organizations/org_123456/enabled_connections")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"connection_id": "con_a", "assign_membership_on_login": false, "show_as_button": true},
+                    {"connection_id": "con_x", "assign_membership_on_login": false, "show_as_button": true}
+                ]"#,
+            )
+            .create_async()
+            .await;
+        let add = server
+            .mock(
+                "POST",
+                "/api/v2/organizations/org_123456/enabled_connections",
+            )
+            .match_body(Matcher::PartialJsonString(
+                r#"{"connection_id": "con_b"}"#.to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"connection_id": "con_b", "show_as_button": true}"#)
+            .create_async()
+            .await;
+        let update = server
+            .mock(
+                "PATCH",
+                "/api/v2/organizations/org_123456/enabled_connections/con_a",
+            )
+            .match_body(Matcher::PartialJsonString(
+                r#"{"assign_membership_on_login": true}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"connection_id": "con_a", "assign_membership_on_login": true, "show_as_button": true}"#)
+            .create_async()
+            .await;
+        let remove = server
+            .mock(
+                "DELETE",
+                "/api/v2/organizations/org_123456/enabled_connections/con_x",
+            )
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("Same".to_string()),
+            enabled_connections: current_org().enabled_connections,
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        patch.assert_async().await;
+        list.assert_async().await;
+        add.assert_async().await;
+        update.assert_async().await;
+        remove.assert_async().await;
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Updated(_)));
+        assert_eq!(
+            outcome.organization().enabled_connections,
+            current_org().enabled_connections
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_matching_connections_unchanged() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let _lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "Same"}"#)
+            .create_async()
+            .await;
+        let current = current_org().enabled_connections.unwrap();
+        let list = server
+            .mock(
+                "GET",
+                "/api/v2/organizations/org_123456/enabled_connections",
+            )
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(serde_json::to_string(&current).unwrap())
+            .create_async()
+            .await;
+        let mut writes = Vec::new();
+        for method in ["POST", "PATCH", "DELETE"] {
+            writes.push(
+                server
+                    .mock(method, Matcher::Any)
+                    .expect(0)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("Same".to_string()),
+            enabled_connections: Some(current.into_iter().rev().collect()),
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        list.assert_async().await;
+        for write in writes {
+            write.assert_async().await;
+        }
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Unchanged(_)));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_conflict_reconciles_existing() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let missing = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let create = server
+            .mock("POST", "/api/v2/organizations")
+            .with_status(409)
+            .with_body(r#"{"message": "An organization with this name already exists."}"#)
+            .create_async()
+            .await;
+        let lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_race", "name": "test-org", "display_name": "Old"}"#)
+            .create_async()
+            .await;
+        let patch = server
+            .mock("PATCH", "/api/v2/organizations/org_race")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"display_name": "New"}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_race", "name": "test-org", "display_name": "New"}"#)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            display_name: Some("New".to_string()),
+            ..Default::default()
+        };
+
+        let outcome = ensure_organization(&domain, &token, desired).await.unwrap();
+        missing.assert_async().await;
+        create.assert_async().await;
+        lookup.assert_async().await;
+        patch.assert_async().await;
+
+        assert!(matches!(outcome, EnsureOrganizationOutcome::Updated(_)));
+        assert_eq!(outcome.organization().id, "org_race");
+    }
+
+    #[tokio::test]
+    async fn test_ensure_organization_conflict_without_organization() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let lookup = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .with_status(404)
+            .expect(2)
+            .create_async()
+            .await;
+        let _create = server
+            .mock("POST", "/api/v2/organizations")
+            .with_status(409)
+            .with_body(r#"{"message": "Conflict"}"#)
+            .create_async()
+            .await;
+
+        let desired = CreateOrganizationRequest {
+            name: "test-org".to_string(),
+            ..Default::default()
+        };
+
+        let result = ensure_organization(&domain, &token, desired).await;
+        lookup.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Conflict { .. })));
+    }
+}
@@ -0,0 +1,228 @@
+//! List the client grants associated with an organization
+//!
+//! This module provides the `get_client_grants` function for listing the client grants
+//! associated with an organization in Auth0. It wraps the
+//! GET /api/v2/organizations/{id}/client-grants endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     organizations::{GetClientGrantsQuery, get_client_grants},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let query = GetClientGrantsQuery {
+//!         audience: Some("https://api.example.com".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     let grants = get_client_grants(&domain, &token, "org_123456", query).await?;
+//!     for grant in grants {
+//!         println!("{} -> {}", grant.client_id, grant.audience);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/organizations/get-organization-client-grants)
+//! for more details about the list organization client grants endpoint.
+
+use crate::{
+    client_grants::ClientGrant,
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing the client grants of an organization
+///
+/// All fields are optional. Only fields that are provided are sent as filters.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GetClientGrantsQuery {
+    /// Only return grants for this audience
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Only return grants for this client ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Only return grants with these IDs (comma-separated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_ids: Option<String>,
+
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page. Defaults to 50.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Lists the client grants associated with an organization in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:organization_client_grants` scope
+/// * `organization_id` - The ID of the organization
+/// * `query` - Optional filters and pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_client_grants(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    query: GetClientGrantsQuery,
+) -> Result<Vec<ClientGrant>> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/client-grants"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the client grants
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<ClientGrant>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_get_client_grants_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let response_body = r#"[
+            {
+                "id": "cgr_1",
+                "client_id": "client_abc",
+                "audience": "https://api.example.com",
+                "scope": ["read:things", "write:things"]
+            },
+            {
+                "id": "cgr_2",
+                "client_id": "client_def",
+                "audience": "https://api.example.com",
+                "scope": []
+            }
+        ]"#;
+
+        let mock = server
+            .mock("GET", "/api/v2/organizations/org_123456/client-grants")
+            .match_header("Authorization", "Bearer test-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("audience".into(), "https://api.example.com".into()),
+                Matcher::UrlEncoded("per_page".into(), "10".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(response_body)
+            .create_async()
+            .await;
+
+        let query = GetClientGrantsQuery {
+            audience: Some("https://api.example.com".to_string()),
+            per_page: Some(10),
+            ..Default::default()
+        };
+
+        let result = get_client_grants(&domain, &token, "org_123456", query).await;
+        mock.assert_async().await;
+
+        let grants = result.unwrap();
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].scope.len(), 2);
+        assert_eq!(grants[1].client_id, "client_def");
+    }
+
+    #[tokio::test]
+    async fn test_get_client_grants_empty_organization_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_client_grants(&domain, &token, "", Default::default()).await;
+
+        match result.unwrap_err() {
+            Auth0Error::InvalidRequest(msg) => {
+                assert_eq!(msg, "Organization ID cannot be empty");
+            }
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_client_grants_forbidden() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/organizations/org_123456/client-grants")
+            .with_status(403)
+            .with_body(r#"{"statusCode": 403, "message": "Insufficient scope"}"#)
+            .create_async()
+            .await;
+
+        let result = get_client_grants(&domain, &token, "org_123456", Default::default()).await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            Auth0Error::Forbidden(msg) => assert!(msg.contains("Insufficient scope")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Organization lookup by name for Auth0 Management API v2
+//!
+//! This module provides the `get_organization_by_name` function for fetching an organization
+//! by its unique name. It wraps the GET /api/v2/organizations/name/{name} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/organizations/get-name-by-name)
+//! for more details about the get organization by name endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::create_organization::OrganizationResponse;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches an organization by its name.
+///
+/// Returns `Ok(None)` if no organization with the given name exists.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:organizations` scope
+/// * `name` - The unique name (slug) of the organization
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The name is empty
+/// * The request fails due to network issues
+/// * The API returns an error response other than 404
+/// * The response cannot be deserialized
+pub async fn get_organization_by_name(
+    domain: &Domain,
+    token: &BearerToken,
+    name: &str,
+) -> Result<Option<OrganizationResponse>> {
+    // Validate name
+    if name.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization name cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain and organization name
+    let endpoint = domain.to_url(&format!("/api/v2/organizations/name/{name}"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the organization
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<OrganizationResponse>()
+            .await
+            .map(Some)
+            .map_err(Auth0Error::from),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_organization_by_name_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/organizations/name/test-org")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "org_123456", "name": "test-org", "display_name": "Test"}"#)
+            .create_async()
+            .await;
+
+        let result = get_organization_by_name(&domain, &token, "test-org").await;
+        mock.assert_async().await;
+
+        let org = result.unwrap().expect("Organization should exist");
+        assert_eq!(org.id, "org_123456");
+        assert_eq!(org.display_name, Some("Test".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_organization_by_name_not_found() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/organizations/name/missing-org")
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "No organization found by that name"}"#)
+            .create_async()
+            .await;
+
+        let result = get_organization_by_name(&domain, &token, "missing-org").await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().is_none());
+    }
+}
@@ -0,0 +1,233 @@
+//! Associate a client grant with an organization
+//!
+//! This module provides the `post_client_grants` function for associating an existing
+//! client grant with an organization in Auth0. It wraps the
+//! POST /api/v2/organizations/{id}/client-grants endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     organizations::{AssociateClientGrantRequest, post_client_grants},
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = AssociateClientGrantRequest {
+//!         grant_id: "cgr_123456".to_string(),
+//!     };
+//!
+//!     let grant = post_client_grants(&domain, &token, "org_123456", request).await?;
+//!     println!("Associated client grant {} for client {}", grant.id, grant.client_id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/organizations/create-organization-client-grant)
+//! for more details about the associate client grant endpoint.
+
+use crate::{
+    client_grants::ClientGrant,
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for associating a client grant with an organization
+///
+/// See: <https://auth0.com/docs/api/management/v2/organizations/create-organization-client-grant>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssociateClientGrantRequest {
+    /// The ID of the client grant to associate with the organization
+    pub grant_id: String,
+}
+
+/// Associates a client grant with an organization in Auth0.
+///
+/// Once associated, the client can request tokens for the grant's audience on behalf
+/// of the organization using the client credentials flow.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:organization_client_grants` scope
+/// * `organization_id` - The ID of the organization to associate the grant with
+/// * `request` - The request containing the client grant ID
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID or grant ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+///
+/// # Rate Limiting
+///
+/// Auth0 enforces rate limits on Management API endpoints. If you receive a 429 error,
+/// implement exponential backoff before retrying.
+pub async fn post_client_grants(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    request: AssociateClientGrantRequest,
+) -> Result<ClientGrant> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate grant_id
+    if request.grant_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Grant ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/client-grants"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to associate the client grant
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Extract the status code before consuming the response
+    let status = response.status();
+
+    // Handle the response based on status code
+    match status {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<ClientGrant>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::OrganizationUsage;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_post_client_grants_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = AssociateClientGrantRequest {
+            grant_id: "cgr_123".to_string(),
+        };
+
+        let response_body = r#"{
+            "id": "cgr_123",
+            "client_id": "client_abc",
+            "audience": "https://api.example.com",
+            "scope": ["read:things"],
+            "organization_usage": "require",
+            "allow_any_organization": false
+        }"#;
+
+        let mock = server
+            .mock("POST", "/api/v2/organizations/org_123456/client-grants")
+            .match_header("Authorization", "Bearer test-token")
+            .match_header("Content-Type", "application/json")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"grant_id": "cgr_123"}"#.to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(response_body)
+            .create_async()
+            .await;
+
+        let result = post_client_grants(&domain, &token, "org_123456", request).await;
+        mock.assert_async().await;
+
+        let grant = result.unwrap();
+        assert_eq!(grant.id, "cgr_123");
+        assert_eq!(grant.client_id, "client_abc");
+        assert_eq!(grant.scope, vec!["read:things".to_string()]);
+        assert_eq!(grant.organization_usage, Some(OrganizationUsage::Require));
+    }
+
+    #[tokio::test]
+    async fn test_post_client_grants_empty_grant_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = AssociateClientGrantRequest {
+            grant_id: String::new(),
+        };
+
+        let result = post_client_grants(&domain, &token, "org_123456", request).await;
+
+        match result.unwrap_err() {
+            Auth0Error::InvalidRequest(msg) => {
+                assert_eq!(msg, "Grant ID cannot be empty");
+            }
+            _ => panic!("Expected InvalidRequest error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_client_grants_conflict() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = AssociateClientGrantRequest {
+            grant_id: "cgr_123".to_string(),
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/organizations/org_123456/client-grants")
+            .with_status(409)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"statusCode": 409, "message": "Grant already associated"}"#)
+            .create_async()
+            .await;
+
+        let result = post_client_grants(&domain, &token, "org_123456", request).await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            Auth0Error::Conflict { status, body } => {
+                assert_eq!(status, 409);
+                assert!(body.contains("Grant already associated"));
+            }
+            _ => panic!("Expected Conflict error"),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Remove a client grant from an organization
+//!
+//! This module provides the `remove_organization_client_grant` function for disassociating a client
+//! grant from an organization in Auth0. It wraps the
+//! DELETE /api/v2/organizations/{id}/client-grants/{grant_id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/organizations/delete-client-grants-by-grant-id)
+//! for more details about the remove client grant endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Removes a client grant from an organization in Auth0.
+///
+/// The client grant itself is not deleted; it is only disassociated from the organization.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:organization_client_grants` scope
+/// * `organization_id` - The ID of the organization
+/// * `grant_id` - The ID of the client grant to remove
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The organization ID or grant ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn remove_organization_client_grant(
+    domain: &Domain,
+    token: &BearerToken,
+    organization_id: &str,
+    grant_id: &str,
+) -> Result<()> {
+    // Validate organization_id
+    if organization_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Organization ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Validate grant_id
+    if grant_id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Grant ID cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url(&format!(
+        "/api/v2/organizations/{organization_id}/client-grants/{grant_id}"
+    ));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the client grant
+    let response = client
+        .delete(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_remove_organization_client_grant_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "DELETE",
+                "/api/v2/organizations/org_123456/client-grants/cgr_123",
+            )
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result =
+            remove_organization_client_grant(&domain, &token, "org_123456", "cgr_123").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_remove_organization_client_grant_empty_ids() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = remove_organization_client_grant(&domain, &token, "", "cgr_123").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+
+        let result = remove_organization_client_grant(&domain, &token, "org_123456", "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_remove_organization_client_grant_not_found() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "DELETE",
+                "/api/v2/organizations/org_123456/client-grants/cgr_missing",
+            )
+            .with_status(404)
+            .with_body(r#"{"statusCode": 404, "message": "Client grant not found"}"#)
+            .create_async()
+            .await;
+
+        let result =
+            remove_organization_client_grant(&domain, &token, "org_123456", "cgr_missing").await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            Auth0Error::UnexpectedResponse { status, body } => {
+                assert_eq!(status, 404);
+                assert!(body.contains("Client grant not found"));
+            }
+            _ => panic!("Expected UnexpectedResponse error with 404 status"),
+        }
+    }
+}
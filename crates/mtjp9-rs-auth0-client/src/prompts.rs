@@ -0,0 +1,15 @@
+//! Prompt functionality for Auth0
+//!
+//! This module provides functions for managing the Universal Login prompts through the
+//! Auth0 Management API v2: the settings of the login flow, the custom text of each
+//! prompt per language and the partials inserted into its screens.
+
+mod custom_text;
+mod get_prompts;
+mod partials;
+mod update_prompts;
+
+pub use custom_text::{get_custom_text, put_custom_text, CustomText};
+pub use get_prompts::{get_prompts, PromptName, PromptSettings, UniversalLoginExperience};
+pub use partials::{get_prompt_partials, put_prompt_partials, PromptPartials};
+pub use update_prompts::{update_prompts, UpdatePromptsRequest};
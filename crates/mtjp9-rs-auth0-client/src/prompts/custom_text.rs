@@ -0,0 +1,257 @@
+//! Custom text management for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and setting the text of the screens of a
+//! prompt in one language. It wraps the /api/v2/prompts/{prompt}/custom-text/{language}
+//! endpoints.
+//!
+//! Custom text maps each screen of the prompt to its texts by key, which is also the shape
+//! of the JSON files the dashboard exports. Keys left out use the default text.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     prompts::{CustomText, PromptName, put_custom_text},
+//!     error::Auth0Error,
+//! };
+//! use std::{env, fs};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // One file per language checked into the repository, e.g. `prompts/login/ja.json`
+//!     for language in ["en", "ja"] {
+//!         let json = fs::read_to_string(format!("prompts/login/{language}.json"))
+//!             .map_err(|e| Auth0Error::InvalidRequest(e.to_string()))?;
+//!         let text: CustomText = serde_json::from_str(&json)
+//!             .map_err(|e| Auth0Error::InvalidRequest(e.to_string()))?;
+//!
+//!         put_custom_text(&domain, &token, &PromptName::Login, language, &text).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/prompts/put-custom-text-by-language)
+//! for more details about the custom text endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode, Url};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::get_prompts::PromptName;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Texts of the screens of a prompt, by screen and by key
+pub type CustomText = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Builds the custom text URL, encoding the prompt and language as path segments
+fn custom_text_url(domain: &Domain, prompt: &PromptName, language: &str) -> Result<Url> {
+    // Validate language: a language tag such as `en`, `pt-BR` or `es-419`
+    if language.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Language cannot be empty".to_string(),
+        ));
+    }
+    if !language
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(Auth0Error::InvalidRequest(format!(
+            "Invalid language tag: {language}"
+        )));
+    }
+
+    let mut url = Url::parse(&domain.to_url("/api/v2/prompts"))
+        .map_err(|e| Auth0Error::InvalidRequest(format!("Invalid URL: {e}")))?;
+    url.path_segments_mut()
+        .map_err(|_| Auth0Error::InvalidRequest("Invalid URL".to_string()))?
+        .extend([prompt.as_str(), "custom-text", language]);
+
+    Ok(url)
+}
+
+/// Fetches the custom text of a prompt in one language.
+///
+/// The result is empty if no text was customized.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:prompts` scope
+/// * `prompt` - The prompt
+/// * `language` - The language code, such as `en` or `ja`
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the language is not a valid language tag, the request fails
+/// or the API returns an error response.
+pub async fn get_custom_text(
+    domain: &Domain,
+    token: &BearerToken,
+    prompt: &PromptName,
+    language: &str,
+) -> Result<CustomText> {
+    // Validate the language and construct the API endpoint URL
+    let endpoint = custom_text_url(domain, prompt, language)?;
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the custom text
+    let response = client
+        .get(endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<CustomText>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Sets the custom text of a prompt in one language.
+///
+/// The text replaces all custom text of the prompt in that language.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:prompts` scope
+/// * `prompt` - The prompt
+/// * `language` - The language code, such as `en` or `ja`
+/// * `text` - The texts by screen and key
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the language is not a valid language tag, the request fails
+/// or the API returns an error response, such as 400 for an unknown screen or key.
+pub async fn put_custom_text(
+    domain: &Domain,
+    token: &BearerToken,
+    prompt: &PromptName,
+    language: &str,
+    text: &CustomText,
+) -> Result<()> {
+    // Validate the language and construct the API endpoint URL
+    let endpoint = custom_text_url(domain, prompt, language)?;
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the custom text
+    let response = client
+        .put(endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(text)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_put_custom_text_japanese() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let body = r#"{"login": {"title": "ようこそ", "description": "続行するにはログインしてください"}}"#;
+        let mock = server
+            .mock("PUT", "/api/v2/prompts/login/custom-text/ja")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(body.to_string()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let text: CustomText = serde_json::from_str(body).unwrap();
+        let result = put_custom_text(&domain, &token, &PromptName::Login, "ja", &text).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_custom_text_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/prompts/signup-id/custom-text/en")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"signup-id": {"title": "Create your account"}}"#)
+            .create_async()
+            .await;
+
+        let result = get_custom_text(&domain, &token, &PromptName::SignupId, "en").await;
+        mock.assert_async().await;
+
+        let text = result.unwrap();
+        assert_eq!(text["signup-id"]["title"], "Create your account");
+    }
+
+    #[tokio::test]
+    async fn test_get_custom_text_empty_language() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_custom_text(&domain, &token, &PromptName::Login, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_custom_text_rejects_path_in_language() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        for language in ["../partials", "en?x=1", "en/us"] {
+            let result = get_custom_text(&domain, &token, &PromptName::Login, language).await;
+            assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+        }
+    }
+
+    #[test]
+    fn test_custom_text_url_encodes_prompt() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let prompt = PromptName::from("a/b".to_string());
+
+        let url = custom_text_url(&domain, &prompt, "pt-BR").unwrap();
+        assert_eq!(url.path(), "/api/v2/prompts/a%2Fb/custom-text/pt-BR");
+    }
+}
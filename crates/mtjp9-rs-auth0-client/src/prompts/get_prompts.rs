@@ -0,0 +1,233 @@
+//! Prompt settings retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_prompts` function for fetching the settings of the login
+//! flow, such as whether users enter their identifier before their password. It wraps the
+//! GET /api/v2/prompts endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/prompts/get-prompts)
+//! for more details about the get prompts endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Which Universal Login pages the tenant uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UniversalLoginExperience {
+    New,
+    Classic,
+}
+
+/// Settings of the login flow
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PromptSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universal_login_experience: Option<UniversalLoginExperience>,
+
+    /// Whether users enter their identifier first and their password on a second screen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_first: Option<bool>,
+
+    /// Whether users may log in with a device biometric instead of a password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webauthn_platform_first_factor: Option<bool>,
+}
+
+/// A Universal Login prompt, a group of screens of the login flow
+///
+/// Prompts not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PromptName {
+    Login,
+    LoginId,
+    LoginPassword,
+    LoginPasswordless,
+    Signup,
+    SignupId,
+    SignupPassword,
+    ResetPassword,
+    Consent,
+    CustomizedConsent,
+    Mfa,
+    MfaOtp,
+    MfaEmail,
+    MfaSms,
+    EmailVerification,
+    Organizations,
+    Invitation,
+    Logout,
+    Common,
+    /// Any other prompt
+    Other(String),
+}
+
+impl PromptName {
+    /// The name Auth0 uses for this prompt.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Login => "login",
+            Self::LoginId => "login-id",
+            Self::LoginPassword => "login-password",
+            Self::LoginPasswordless => "login-passwordless",
+            Self::Signup => "signup",
+            Self::SignupId => "signup-id",
+            Self::SignupPassword => "signup-password",
+            Self::ResetPassword => "reset-password",
+            Self::Consent => "consent",
+            Self::CustomizedConsent => "customized-consent",
+            Self::Mfa => "mfa",
+            Self::MfaOtp => "mfa-otp",
+            Self::MfaEmail => "mfa-email",
+            Self::MfaSms => "mfa-sms",
+            Self::EmailVerification => "email-verification",
+            Self::Organizations => "organizations",
+            Self::Invitation => "invitation",
+            Self::Logout => "logout",
+            Self::Common => "common",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for PromptName {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "login" => Self::Login,
+            "login-id" => Self::LoginId,
+            "login-password" => Self::LoginPassword,
+            "login-passwordless" => Self::LoginPasswordless,
+            "signup" => Self::Signup,
+            "signup-id" => Self::SignupId,
+            "signup-password" => Self::SignupPassword,
+            "reset-password" => Self::ResetPassword,
+            "consent" => Self::Consent,
+            "customized-consent" => Self::CustomizedConsent,
+            "mfa" => Self::Mfa,
+            "mfa-otp" => Self::MfaOtp,
+            "mfa-email" => Self::MfaEmail,
+            "mfa-sms" => Self::MfaSms,
+            "email-verification" => Self::EmailVerification,
+            "organizations" => Self::Organizations,
+            "invitation" => Self::Invitation,
+            "logout" => Self::Logout,
+            "common" => Self::Common,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<PromptName> for String {
+    fn from(name: PromptName) -> Self {
+        match name {
+            PromptName::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PromptName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Fetches the settings of the login flow.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:prompts` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_prompts(domain: &Domain, token: &BearerToken) -> Result<PromptSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/prompts");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the prompt settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<PromptSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_prompts_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/prompts")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"universal_login_experience": "new", "identifier_first": true, "webauthn_platform_first_factor": false}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_prompts(&domain, &token).await;
+        mock.assert_async().await;
+
+        let settings = result.unwrap();
+        assert_eq!(
+            settings.universal_login_experience,
+            Some(UniversalLoginExperience::New)
+        );
+        assert_eq!(settings.identifier_first, Some(true));
+    }
+
+    #[test]
+    fn test_prompt_name_round_trip() {
+        assert_eq!(
+            PromptName::from("login-id".to_string()),
+            PromptName::LoginId
+        );
+        assert_eq!(
+            PromptName::LoginPasswordless.to_string(),
+            "login-passwordless"
+        );
+        assert_eq!(
+            String::from(PromptName::from("passkeys".to_string())),
+            "passkeys"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Prompt partials management for Auth0 Management API v2
+//!
+//! This module provides functions for fetching and setting the partials of a prompt, HTML
+//! fragments inserted at fixed points of its screens. It wraps the
+//! /api/v2/prompts/{prompt}/partials endpoints. Partials require a custom domain and a
+//! Universal Login page template.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/prompts/put-partials)
+//! for more details about the partials endpoints.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::get_prompts::PromptName;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// HTML fragments of the screens of a prompt, by screen and by insertion point such as
+/// `form-content-start`
+pub type PromptPartials = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Fetches the partials of a prompt.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:prompts` scope
+/// * `prompt` - The prompt
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response.
+pub async fn get_prompt_partials(
+    domain: &Domain,
+    token: &BearerToken,
+    prompt: &PromptName,
+) -> Result<PromptPartials> {
+    // Construct the API endpoint URL using the provided domain and prompt
+    let endpoint = domain.to_url(&format!("/api/v2/prompts/{prompt}/partials"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the partials
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<PromptPartials>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+/// Sets the partials of a prompt, replacing all existing ones.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:prompts` scope
+/// * `prompt` - The prompt
+/// * `partials` - The HTML fragments by screen and insertion point
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if the request fails or the API returns an error response,
+/// such as 400 for a prompt that does not support partials.
+pub async fn put_prompt_partials(
+    domain: &Domain,
+    token: &BearerToken,
+    prompt: &PromptName,
+    partials: &PromptPartials,
+) -> Result<()> {
+    // Construct the API endpoint URL using the provided domain and prompt
+    let endpoint = domain.to_url(&format!("/api/v2/prompts/{prompt}/partials"));
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PUT request to set the partials
+    let response = client
+        .put(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(partials)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_put_prompt_partials_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PUT", "/api/v2/prompts/signup/partials")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"signup": {"form-content-end": "<label><input type=\"checkbox\" name=\"ulp-terms\"> I agree</label>"}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let mut partials = PromptPartials::new();
+        partials.entry("signup".to_string()).or_default().insert(
+            "form-content-end".to_string(),
+            r#"<label><input type="checkbox" name="ulp-terms"> I agree</label>"#.to_string(),
+        );
+        let result = put_prompt_partials(&domain, &token, &PromptName::Signup, &partials).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_prompt_partials_unsupported_prompt() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/prompts/mfa/partials")
+            .with_status(400)
+            .with_body(r#"{"message": "Partials are not supported for this prompt"}"#)
+            .create_async()
+            .await;
+
+        let result = get_prompt_partials(&domain, &token, &PromptName::Mfa).await;
+        mock.assert_async().await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Prompt settings updates for Auth0 Management API v2
+//!
+//! This module provides the `update_prompts` function for changing the settings of the
+//! login flow. It wraps the PATCH /api/v2/prompts endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/prompts/patch-prompts)
+//! for more details about the update prompts endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::get_prompts::{PromptSettings, UniversalLoginExperience};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating the prompt settings
+///
+/// All fields are optional. Only fields that are provided will be updated.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdatePromptsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universal_login_experience: Option<UniversalLoginExperience>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_first: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webauthn_platform_first_factor: Option<bool>,
+}
+
+/// Updates the settings of the login flow.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:prompts` scope
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_prompts(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UpdatePromptsRequest,
+) -> Result<PromptSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/prompts");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the prompt settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<PromptSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_prompts_identifier_first() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/prompts")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"universal_login_experience": "new", "identifier_first": true}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"universal_login_experience": "new", "identifier_first": true}"#)
+            .create_async()
+            .await;
+
+        let request = UpdatePromptsRequest {
+            universal_login_experience: Some(UniversalLoginExperience::New),
+            identifier_first: Some(true),
+            ..Default::default()
+        };
+        let result = update_prompts(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().identifier_first, Some(true));
+    }
+}
@@ -0,0 +1,19 @@
+//! Resource server (API) management functionality for Auth0
+//!
+//! This module provides functions for registering and maintaining APIs and their
+//! permissions through the Auth0 Management API v2.
+
+mod create_resource_server;
+mod delete_resource_server;
+mod get_resource_server;
+mod list_resource_servers;
+mod update_resource_server;
+
+pub use create_resource_server::{
+    create_resource_server, CreateResourceServerRequest, ResourceServer, ResourceServerScope,
+    SigningAlgorithm, TokenDialect,
+};
+pub use delete_resource_server::delete_resource_server;
+pub use get_resource_server::get_resource_server;
+pub use list_resource_servers::{list_resource_servers, ListResourceServersQuery};
+pub use update_resource_server::{update_resource_server, UpdateResourceServerRequest};
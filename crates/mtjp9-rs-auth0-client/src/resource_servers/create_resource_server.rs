@@ -0,0 +1,384 @@
+//! Resource server (API) creation functionality for Auth0 Management API v2
+//!
+//! This module provides the `create_resource_server` function for registering a new API
+//! in Auth0. It wraps the POST /api/v2/resource-servers endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     resource_servers::{
+//!         CreateResourceServerRequest, ResourceServerScope, SigningAlgorithm,
+//!         create_resource_server,
+//!     },
+//!     error::Auth0Error,
+//! };
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     let request = CreateResourceServerRequest {
+//!         identifier: "https://orders.example.com".to_string(),
+//!         name: Some("Orders API".to_string()),
+//!         signing_alg: Some(SigningAlgorithm::RS256),
+//!         scopes: Some(vec![ResourceServerScope::new("read:orders", "Read orders")]),
+//!         ..Default::default()
+//!     };
+//!
+//!     let api = create_resource_server(&domain, &token, request).await?;
+//!     println!("Created resource server {} ({})", api.identifier, api.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/resource-servers/post-resource-servers)
+//! for more details about the resource server creation endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Algorithm used to sign access tokens issued for the resource server
+///
+/// Algorithms not modelled here are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SigningAlgorithm {
+    /// HMAC with SHA-256, using the resource server's signing secret
+    HS256,
+    /// RSA PKCS#1 v1.5 with SHA-256, using the tenant's signing key
+    RS256,
+    /// RSA-PSS with SHA-256, using the tenant's signing key
+    PS256,
+    /// Any other algorithm, preserved as sent by Auth0
+    Other(String),
+}
+
+impl SigningAlgorithm {
+    /// The name Auth0 uses for this algorithm.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::HS256 => "HS256",
+            Self::RS256 => "RS256",
+            Self::PS256 => "PS256",
+            Self::Other(alg) => alg,
+        }
+    }
+}
+
+impl From<String> for SigningAlgorithm {
+    fn from(alg: String) -> Self {
+        match alg.as_str() {
+            "HS256" => Self::HS256,
+            "RS256" => Self::RS256,
+            "PS256" => Self::PS256,
+            _ => Self::Other(alg),
+        }
+    }
+}
+
+impl From<SigningAlgorithm> for String {
+    fn from(alg: SigningAlgorithm) -> Self {
+        match alg {
+            SigningAlgorithm::Other(alg) => alg,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Dialect of the access tokens issued for the resource server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenDialect {
+    /// Auth0 access token profile
+    AccessToken,
+
+    /// Auth0 access token profile including a `permissions` claim
+    AccessTokenAuthz,
+
+    /// RFC 9068 access token profile
+    Rfc9068Profile,
+
+    /// RFC 9068 access token profile including a `permissions` claim
+    Rfc9068ProfileAuthz,
+}
+
+/// A scope (permission) defined by a resource server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceServerScope {
+    /// The scope name (e.g. "read:orders")
+    pub value: String,
+
+    /// A description of the scope
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl ResourceServerScope {
+    /// Creates a scope with a description.
+    pub fn new(value: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            description: Some(description.into()),
+        }
+    }
+}
+
+/// Request body for creating a resource server
+///
+/// See: <https://auth0.com/docs/api/management/v2/resource-servers/post-resource-servers>
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateResourceServerRequest {
+    /// Unique identifier (audience) of the API, usually a URL. Cannot be changed later.
+    pub identifier: String,
+
+    /// A friendly name for the API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The scopes (permissions) the API supports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ResourceServerScope>>,
+
+    /// Algorithm used to sign access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_alg: Option<SigningAlgorithm>,
+
+    /// Secret used to sign tokens when `signing_alg` is `HS256`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<String>,
+
+    /// Whether refresh tokens can be issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_offline_access: Option<bool>,
+
+    /// Lifetime in seconds of access tokens issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u32>,
+
+    /// Lifetime in seconds of access tokens issued through the implicit or hybrid flows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime_for_web: Option<u32>,
+
+    /// Whether role based access control policies are enforced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_policies: Option<bool>,
+
+    /// Dialect of the issued access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_dialect: Option<TokenDialect>,
+
+    /// Whether consent is skipped for verifiable first party clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+}
+
+/// Response from creating or fetching a resource server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceServer {
+    /// The resource server's unique identifier
+    pub id: String,
+
+    /// Unique identifier (audience) of the API
+    pub identifier: String,
+
+    /// A friendly name for the API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Whether this is an Auth0 system API (e.g. the Management API)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_system: Option<bool>,
+
+    /// The scopes (permissions) the API supports
+    #[serde(default)]
+    pub scopes: Vec<ResourceServerScope>,
+
+    /// Algorithm used to sign access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_alg: Option<SigningAlgorithm>,
+
+    /// Whether refresh tokens can be issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_offline_access: Option<bool>,
+
+    /// Lifetime in seconds of access tokens issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u32>,
+
+    /// Lifetime in seconds of access tokens issued through the implicit or hybrid flows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime_for_web: Option<u32>,
+
+    /// Whether role based access control policies are enforced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_policies: Option<bool>,
+
+    /// Dialect of the issued access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_dialect: Option<TokenDialect>,
+
+    /// Whether consent is skipped for verifiable first party clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+}
+
+/// Creates a resource server (API) in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `create:resource_servers` scope
+/// * `request` - The resource server configuration
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The identifier is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn create_resource_server(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateResourceServerRequest,
+) -> Result<ResourceServer> {
+    // Validate identifier
+    if request.identifier.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Resource server identifier cannot be empty".to_string(),
+        ));
+    }
+
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/resource-servers");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the POST request to create the resource server
+    let response = client
+        .post(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::CREATED | StatusCode::OK => response
+            .json::<ResourceServer>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_create_resource_server_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = CreateResourceServerRequest {
+            identifier: "https://orders.example.com".to_string(),
+            name: Some("Orders API".to_string()),
+            signing_alg: Some(SigningAlgorithm::RS256),
+            scopes: Some(vec![ResourceServerScope::new("read:orders", "Read orders")]),
+            token_lifetime: Some(3600),
+            enforce_policies: Some(true),
+            token_dialect: Some(TokenDialect::AccessTokenAuthz),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("POST", "/api/v2/resource-servers")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                serde_json::to_string(&request).unwrap(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "rs_1",
+                    "identifier": "https://orders.example.com",
+                    "name": "Orders API",
+                    "signing_alg": "RS256",
+                    "scopes": [{"value": "read:orders", "description": "Read orders"}],
+                    "token_lifetime": 3600,
+                    "enforce_policies": true,
+                    "token_dialect": "access_token_authz"
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = create_resource_server(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        let api = result.unwrap();
+        assert_eq!(api.id, "rs_1");
+        assert_eq!(api.scopes[0].value, "read:orders");
+        assert_eq!(api.token_dialect, Some(TokenDialect::AccessTokenAuthz));
+    }
+
+    #[tokio::test]
+    async fn test_create_resource_server_empty_identifier() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = create_resource_server(&domain, &token, Default::default()).await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_token_dialect_serialization() {
+        assert_eq!(
+            serde_json::to_value(TokenDialect::Rfc9068ProfileAuthz).unwrap(),
+            "rfc9068_profile_authz"
+        );
+        assert_eq!(
+            serde_json::to_value(SigningAlgorithm::PS256).unwrap(),
+            "PS256"
+        );
+    }
+
+    #[test]
+    fn test_unknown_signing_algorithm() {
+        let alg: SigningAlgorithm = serde_json::from_str(r#""ES256""#).unwrap();
+        assert_eq!(alg, SigningAlgorithm::Other("ES256".to_string()));
+        assert_eq!(serde_json::to_value(alg).unwrap(), "ES256");
+    }
+}
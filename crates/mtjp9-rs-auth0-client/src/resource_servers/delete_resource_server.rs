@@ -0,0 +1,104 @@
+//! Resource server (API) deletion for Auth0 Management API v2
+//!
+//! This module provides the `delete_resource_server` function for deleting an API.
+//! It wraps the DELETE /api/v2/resource-servers/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/resource-servers/delete-resource-servers-by-id)
+//! for more details about the delete resource server endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+
+use super::get_resource_server::resource_server_url;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deletes a resource server (API).
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `delete:resource_servers` scope
+/// * `id` - The ID or identifier (audience) of the resource server
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+pub async fn delete_resource_server(domain: &Domain, token: &BearerToken, id: &str) -> Result<()> {
+    // Construct the API endpoint URL using the provided domain and resource server ID
+    let endpoint = resource_server_url(domain, id)?;
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the DELETE request to remove the resource server
+    let response = client
+        .delete(endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_delete_resource_server_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/resource-servers/rs_1")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let result = delete_resource_server(&domain, &token, "rs_1").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_resource_server_system_api_forbidden() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("DELETE", "/api/v2/resource-servers/rs_mgmt")
+            .with_status(403)
+            .with_body(
+                r#"{"statusCode": 403, "message": "System resource servers cannot be deleted"}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = delete_resource_server(&domain, &token, "rs_mgmt").await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Forbidden(_))));
+    }
+}
@@ -0,0 +1,127 @@
+//! Resource server (API) retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_resource_server` function for fetching a single API.
+//! It wraps the GET /api/v2/resource-servers/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/resource-servers/get-resource-servers-by-id)
+//! for more details about the get resource server endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode, Url};
+use std::time::Duration;
+
+use super::create_resource_server::ResourceServer;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Builds the URL of a single resource server.
+///
+/// Resource servers can be addressed by their ID or by their identifier, which is usually
+/// a URL itself, so the value is percent-encoded as a single path segment.
+pub(super) fn resource_server_url(domain: &Domain, id: &str) -> Result<Url> {
+    if id.is_empty() {
+        return Err(Auth0Error::InvalidRequest(
+            "Resource server ID cannot be empty".to_string(),
+        ));
+    }
+
+    let mut url = Url::parse(&domain.to_url("/api/v2/resource-servers"))
+        .map_err(|e| Auth0Error::InvalidRequest(format!("Invalid URL: {e}")))?;
+    url.path_segments_mut()
+        .map_err(|_| Auth0Error::InvalidRequest("Invalid URL".to_string()))?
+        .push(id);
+
+    Ok(url)
+}
+
+/// Fetches a resource server (API) by its ID or identifier.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:resource_servers` scope
+/// * `id` - The ID or identifier (audience) of the resource server
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_resource_server(
+    domain: &Domain,
+    token: &BearerToken,
+    id: &str,
+) -> Result<ResourceServer> {
+    // Construct the API endpoint URL using the provided domain and resource server ID
+    let endpoint = resource_server_url(domain, id)?;
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the resource server
+    let response = client
+        .get(endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ResourceServer>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_resource_server_by_identifier() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v2/resource-servers/https:%2F%2Forders.example.com",
+            )
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"id": "rs_1", "identifier": "https://orders.example.com"}"#)
+            .create_async()
+            .await;
+
+        let result = get_resource_server(&domain, &token, "https://orders.example.com").await;
+        mock.assert_async().await;
+
+        let api = result.unwrap();
+        assert_eq!(api.id, "rs_1");
+        assert!(api.scopes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_resource_server_empty_id() {
+        let domain = Domain::new("test.auth0.com").expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let result = get_resource_server(&domain, &token, "").await;
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,118 @@
+//! Resource server (API) listing for Auth0 Management API v2
+//!
+//! This module provides the `list_resource_servers` function for listing APIs.
+//! It wraps the GET /api/v2/resource-servers endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/resource-servers/get-resource-servers)
+//! for more details about the list resource servers endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_resource_server::ResourceServer;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Query parameters for listing resource servers
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListResourceServersQuery {
+    /// Page index of the results to return. First page is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of results per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Lists the resource servers (APIs) in the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:resource_servers` scope
+/// * `query` - Optional pagination parameters
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn list_resource_servers(
+    domain: &Domain,
+    token: &BearerToken,
+    query: ListResourceServersQuery,
+) -> Result<Vec<ResourceServer>> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/resource-servers");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to list the resource servers
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .query(&query)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<Vec<ResourceServer>>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_list_resource_servers_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/resource-servers")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "rs_mgmt", "identifier": "https://tenant.auth0.com/api/v2/", "is_system": true},
+                    {"id": "rs_1", "identifier": "https://orders.example.com"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let query = ListResourceServersQuery {
+            page: Some(1),
+            ..Default::default()
+        };
+
+        let result = list_resource_servers(&domain, &token, query).await;
+        mock.assert_async().await;
+
+        let apis = result.unwrap();
+        assert_eq!(apis.len(), 2);
+        assert_eq!(apis[0].is_system, Some(true));
+    }
+}
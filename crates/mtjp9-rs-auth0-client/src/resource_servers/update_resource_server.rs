@@ -0,0 +1,191 @@
+//! Resource server (API) update functionality for Auth0 Management API v2
+//!
+//! This module provides the `update_resource_server` function for updating an existing API.
+//! It wraps the PATCH /api/v2/resource-servers/{id} endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/resource-servers/patch-resource-servers-by-id)
+//! for more details about the resource server update endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::create_resource_server::{
+    ResourceServer, ResourceServerScope, SigningAlgorithm, TokenDialect,
+};
+use super::get_resource_server::resource_server_url;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating a resource server
+///
+/// All fields are optional. Only fields that are provided will be updated.
+/// `scopes` replaces the full list of scopes, so it should always contain every
+/// permission the API supports.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateResourceServerRequest {
+    /// A friendly name for the API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The scopes (permissions) the API supports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ResourceServerScope>>,
+
+    /// Algorithm used to sign access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_alg: Option<SigningAlgorithm>,
+
+    /// Secret used to sign tokens when `signing_alg` is `HS256`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<String>,
+
+    /// Whether refresh tokens can be issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_offline_access: Option<bool>,
+
+    /// Lifetime in seconds of access tokens issued for this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u32>,
+
+    /// Lifetime in seconds of access tokens issued through the implicit or hybrid flows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime_for_web: Option<u32>,
+
+    /// Whether role based access control policies are enforced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_policies: Option<bool>,
+
+    /// Dialect of the issued access tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_dialect: Option<TokenDialect>,
+
+    /// Whether consent is skipped for verifiable first party clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+}
+
+/// Updates a resource server (API) in Auth0.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:resource_servers` scope
+/// * `id` - The ID or identifier (audience) of the resource server
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The ID is empty
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_resource_server(
+    domain: &Domain,
+    token: &BearerToken,
+    id: &str,
+    request: UpdateResourceServerRequest,
+) -> Result<ResourceServer> {
+    // Construct the API endpoint URL using the provided domain and resource server ID
+    let endpoint = resource_server_url(domain, id)?;
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the resource server
+    let response = client
+        .patch(endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<ResourceServer>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_update_resource_server_scopes() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let request = UpdateResourceServerRequest {
+            scopes: Some(vec![
+                ResourceServerScope::new("read:orders", "Read orders"),
+                ResourceServerScope::new("write:orders", "Write orders"),
+            ]),
+            ..Default::default()
+        };
+
+        let mock = server
+            .mock("PATCH", "/api/v2/resource-servers/rs_1")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"scopes": [
+                    {"value": "read:orders", "description": "Read orders"},
+                    {"value": "write:orders", "description": "Write orders"}
+                ]}"#
+                .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "rs_1",
+                    "identifier": "https://orders.example.com",
+                    "scopes": [
+                        {"value": "read:orders", "description": "Read orders"},
+                        {"value": "write:orders", "description": "Write orders"}
+                    ]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = update_resource_server(&domain, &token, "rs_1", request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().scopes.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_resource_server_bad_request() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/resource-servers/rs_1")
+            .with_status(400)
+            .with_body(r#"{"statusCode": 400, "message": "Payload validation error"}"#)
+            .create_async()
+            .await;
+
+        let result = update_resource_server(&domain, &token, "rs_1", Default::default()).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,12 @@
+//! Tenant functionality for Auth0
+//!
+//! This module provides functions for managing the settings of the tenant through the
+//! Auth0 Management API v2.
+
+mod get_tenant_settings;
+mod update_tenant_settings;
+
+pub use get_tenant_settings::{
+    get_tenant_settings, SessionCookie, SessionCookieMode, TenantFlags, TenantSettings,
+};
+pub use update_tenant_settings::{update_tenant_settings, UpdateTenantSettingsRequest};
@@ -0,0 +1,300 @@
+//! Tenant settings retrieval for Auth0 Management API v2
+//!
+//! This module provides the `get_tenant_settings` function for fetching the settings of
+//! the tenant, such as its session lifetimes and support contacts. It wraps the
+//! GET /api/v2/tenants/settings endpoint.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mtjp9_rs_auth0_client::{
+//!     domain::Domain,
+//!     token::BearerToken,
+//!     tenants::get_tenant_settings,
+//!     error::Auth0Error,
+//! };
+//! use std::{env, time::Duration};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     // Setup domain and token
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!
+//!     // Sessions must end after at most a day, or after an hour of inactivity
+//!     let settings = get_tenant_settings(&domain, &token).await?;
+//!     assert!(settings.session_lifetime().is_some_and(|lifetime| lifetime <= Duration::from_secs(24 * 3600)));
+//!     assert!(settings.idle_session_lifetime().is_some_and(|lifetime| lifetime <= Duration::from_secs(3600)));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/tenants/tenant-settings-route)
+//! for more details about the get tenant settings endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Whether the session cookie outlives the browser session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionCookieMode {
+    /// The cookie is kept until the session expires
+    Persistent,
+    /// The cookie is removed when the browser is closed
+    NonPersistent,
+}
+
+/// Session cookie settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionCookie {
+    pub mode: SessionCookieMode,
+}
+
+/// Feature flags of the tenant
+///
+/// Flags not modelled here are kept in `other`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TenantFlags {
+    /// Whether applications may be registered dynamically (OIDC dynamic registration)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dynamic_client_registration: Option<bool>,
+
+    /// Whether links in emails use the custom domain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_custom_domain_in_emails: Option<bool>,
+
+    /// Whether signing up with an existing email reports that the user exists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_public_signup_user_exists_error: Option<bool>,
+
+    /// Whether the login page hides the enterprise connections of the tenant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_disclose_enterprise_connections: Option<bool>,
+
+    /// Whether refresh tokens are revoked when a refresh token grant is revoked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_refresh_token_grant: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_clickjack_protection_headers: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_ro_grant_types: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_delegation_grant_types: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_tokeninfo_endpoint: Option<bool>,
+
+    /// Any other flag
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Settings of the tenant
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TenantSettings {
+    /// The name shown on the login pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+
+    /// URL of the logo shown on the login pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+
+    /// URLs users may be redirected to after logging out of every application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+
+    /// How long a login session stays valid, in hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_lifetime: Option<f64>,
+
+    /// How long a login session stays valid without activity, in hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_session_lifetime: Option<f64>,
+
+    /// Session lifetime for users who did not choose to be remembered, in hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ephemeral_session_lifetime: Option<f64>,
+
+    /// Idle session lifetime for users who did not choose to be remembered, in hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_ephemeral_session_lifetime: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_cookie: Option<SessionCookie>,
+
+    /// The connection used by the password grant when the request names none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_directory: Option<String>,
+
+    /// The audience of access tokens requested without one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_audience: Option<String>,
+
+    /// Where users are sent when a login flow cannot return to an application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_redirection_uri: Option<String>,
+
+    /// Languages of the login pages; the first one is the default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_locales: Option<Vec<String>>,
+
+    /// Node.js version of Rules and Hooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sandbox_version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<TenantFlags>,
+}
+
+impl TenantSettings {
+    /// The session lifetime as a duration.
+    pub fn session_lifetime(&self) -> Option<Duration> {
+        self.session_lifetime.map(hours)
+    }
+
+    /// The idle session lifetime as a duration.
+    pub fn idle_session_lifetime(&self) -> Option<Duration> {
+        self.idle_session_lifetime.map(hours)
+    }
+}
+
+fn hours(hours: f64) -> Duration {
+    Duration::from_secs_f64(hours.max(0.0) * 3600.0)
+}
+
+/// Fetches the settings of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `read:tenant_settings` scope
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn get_tenant_settings(domain: &Domain, token: &BearerToken) -> Result<TenantSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/tenants/settings");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the GET request to fetch the tenant settings
+    let response = client
+        .get(&endpoint)
+        .bearer_auth(token.as_str())
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<TenantSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_get_tenant_settings_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/tenants/settings")
+            .match_header("Authorization", "Bearer test-token")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{
+                    "friendly_name": "Acme",
+                    "support_email": "support@example.com",
+                    "session_lifetime": 168,
+                    "idle_session_lifetime": 0.5,
+                    "session_cookie": {"mode": "non-persistent"},
+                    "default_audience": "https://api.example.com",
+                    "enabled_locales": ["ja", "en"],
+                    "sandbox_version": "18",
+                    "sandbox_versions_available": ["18", "22"],
+                    "flags": {"enable_custom_domain_in_emails": true, "enable_sso": true}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let result = get_tenant_settings(&domain, &token).await;
+        mock.assert_async().await;
+
+        let settings = result.unwrap();
+        assert_eq!(
+            settings.session_lifetime(),
+            Some(Duration::from_secs(168 * 3600))
+        );
+        assert_eq!(
+            settings.idle_session_lifetime(),
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(
+            settings.session_cookie.unwrap().mode,
+            SessionCookieMode::NonPersistent
+        );
+        let flags = settings.flags.unwrap();
+        assert_eq!(flags.enable_custom_domain_in_emails, Some(true));
+        assert_eq!(flags.other["enable_sso"], Value::Bool(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_tenant_settings_forbidden() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("GET", "/api/v2/tenants/settings")
+            .with_status(403)
+            .with_body(r#"{"message": "Insufficient scope, expected: read:tenant_settings"}"#)
+            .create_async()
+            .await;
+
+        let result = get_tenant_settings(&domain, &token).await;
+        mock.assert_async().await;
+
+        assert!(matches!(result, Err(Auth0Error::Forbidden(_))));
+    }
+}
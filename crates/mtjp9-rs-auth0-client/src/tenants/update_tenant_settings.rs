@@ -0,0 +1,173 @@
+//! Tenant settings updates for Auth0 Management API v2
+//!
+//! This module provides the `update_tenant_settings` function for changing the settings of
+//! the tenant. It wraps the PATCH /api/v2/tenants/settings endpoint.
+//!
+//! # API Documentation
+//!
+//! See the [Auth0 API documentation](https://auth0.com/docs/api/management/v2/tenants/patch-settings)
+//! for more details about the update tenant settings endpoint.
+
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use super::get_tenant_settings::{SessionCookie, TenantFlags, TenantSettings};
+
+// Default timeout for HTTP requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Request body for updating the tenant settings
+///
+/// All fields are optional. Only fields that are provided will be updated. Flags left
+/// out of `flags` keep their value.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdateTenantSettingsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+
+    /// In hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_lifetime: Option<f64>,
+
+    /// In hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_session_lifetime: Option<f64>,
+
+    /// In hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ephemeral_session_lifetime: Option<f64>,
+
+    /// In hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_ephemeral_session_lifetime: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_cookie: Option<SessionCookie>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_directory: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_audience: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_redirection_uri: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_locales: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sandbox_version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<TenantFlags>,
+}
+
+/// Updates the settings of the tenant.
+///
+/// # Arguments
+///
+/// * `domain` - The Auth0 domain to use for the API request
+/// * `token` - A valid Management API access token with the `update:tenant_settings` scope
+/// * `request` - The fields to update
+///
+/// # Errors
+///
+/// Returns an `Auth0Error` if:
+/// * The request fails due to network issues
+/// * The API returns an error response (4xx or 5xx status codes)
+/// * The response cannot be deserialized
+pub async fn update_tenant_settings(
+    domain: &Domain,
+    token: &BearerToken,
+    request: UpdateTenantSettingsRequest,
+) -> Result<TenantSettings> {
+    // Construct the API endpoint URL using the provided domain
+    let endpoint = domain.to_url("/api/v2/tenants/settings");
+
+    // Build the HTTP client with timeout
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+
+    // Send the PATCH request to update the tenant settings
+    let response = client
+        .patch(&endpoint)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    // Handle the response based on status code
+    match response.status() {
+        StatusCode::OK => response
+            .json::<TenantSettings>()
+            .await
+            .map_err(Auth0Error::from),
+        _ => Err(Auth0Error::from_response(response).await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenants::SessionCookieMode;
+    use crate::{domain::Domain, token::BearerToken};
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_update_tenant_settings_sessions() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("PATCH", "/api/v2/tenants/settings")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(Matcher::JsonString(
+                r#"{"session_lifetime": 24.0, "idle_session_lifetime": 1.0, "session_cookie": {"mode": "non-persistent"}, "flags": {"revoke_refresh_token_grant": true}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(
+                r#"{"session_lifetime": 24, "idle_session_lifetime": 1, "session_cookie": {"mode": "non-persistent"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let request = UpdateTenantSettingsRequest {
+            session_lifetime: Some(24.0),
+            idle_session_lifetime: Some(1.0),
+            session_cookie: Some(SessionCookie {
+                mode: SessionCookieMode::NonPersistent,
+            }),
+            flags: Some(TenantFlags {
+                revoke_refresh_token_grant: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = update_tenant_settings(&domain, &token, request).await;
+        mock.assert_async().await;
+
+        assert_eq!(result.unwrap().session_lifetime, Some(24.0));
+    }
+}
@@ -1,7 +1,13 @@
 //! Auth0 Tickets API module
 
+pub mod post_email_verification;
 pub mod post_password_change;
 
+pub use post_email_verification::{
+    create_email_verification_ticket, CreateEmailVerificationTicketRequest,
+    CreateEmailVerificationTicketRequestBuilder, CreateEmailVerificationTicketResponse,
+    VerificationIdentity,
+};
 pub use post_password_change::{
     create_password_change_ticket, CreatePasswordChangeTicketRequest,
     CreatePasswordChangeTicketRequestBuilder, CreatePasswordChangeTicketResponse,
@@ -0,0 +1,289 @@
+//! Create Email Verification Ticket API helper
+//!
+//! This module wraps the **Auth0 Management API v2 – Create Email Verification Ticket** endpoint
+//! (<https://auth0.com/docs/api/management/v2/tickets/post-email-verification>).
+//!
+//! # Example
+//! ```no_run
+//! use mtjp9_rs_auth0_client::tickets::{CreateEmailVerificationTicketRequest, create_email_verification_ticket};
+//! use mtjp9_rs_auth0_client::token::BearerToken;
+//! use mtjp9_rs_auth0_client::domain::Domain;
+//! use mtjp9_rs_auth0_client::error::Auth0Error;
+//! use std::env;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Auth0Error> {
+//!     let token = BearerToken::new(env::var("AUTH0_MGMT_API_TOKEN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_MGMT_API_TOKEN not set".to_string()))?)?;
+//!     let domain = Domain::new(env::var("AUTH0_DOMAIN").map_err(|_| Auth0Error::InvalidRequest("AUTH0_DOMAIN not set".to_string()))?)?;
+//!
+//!     let req = CreateEmailVerificationTicketRequest::builder()
+//!         .user_id("auth0|507f1f77bcf86cd799439011")
+//!         .result_url("https://myapp.com/email-verified")
+//!         .ttl_sec(86400) // 1 day
+//!         .build()?;
+//!
+//!     let ticket = create_email_verification_ticket(&domain, &token, req).await?;
+//!     println!("Email verification ticket URL: {}", ticket.ticket);
+//!     Ok(())
+//! }
+//! ```
+use crate::{
+    domain::Domain,
+    error::{Auth0Error, Result},
+    token::BearerToken,
+};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+/// A secondary identity of a user, used to verify the email of a linked account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationIdentity {
+    /// The user ID of the identity, without the provider prefix.
+    pub user_id: String,
+
+    /// The identity provider of the identity (e.g. "google-oauth2").
+    pub provider: String,
+}
+
+impl VerificationIdentity {
+    pub fn new(user_id: impl Into<String>, provider: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            provider: provider.into(),
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.user_id.is_empty() || self.provider.is_empty() {
+            return Err(Auth0Error::InvalidRequest(
+                "Identity requires a user ID and a provider".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CreateEmailVerificationTicketRequest {
+    /// The user ID for whom the email verification ticket is being created.
+    pub user_id: String,
+
+    /// The URL to redirect to after the email is verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+
+    /// The time-to-live in seconds for the ticket. Default is 432000 seconds (5 days).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl_sec: Option<i32>,
+
+    /// Whether to include the email address as part of the result URL.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "includeEmailInRedirect"
+    )]
+    pub include_email_in_redirect: Option<bool>,
+
+    /// The secondary identity whose email is verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<VerificationIdentity>,
+
+    /// The client ID whose login URL is used as redirect target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// The organization ID to use for the redirect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+}
+
+impl CreateEmailVerificationTicketRequest {
+    pub fn builder() -> CreateEmailVerificationTicketRequestBuilder {
+        CreateEmailVerificationTicketRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct CreateEmailVerificationTicketRequestBuilder {
+    user_id: Option<String>,
+    result_url: Option<String>,
+    ttl_sec: Option<i32>,
+    include_email_in_redirect: Option<bool>,
+    identity: Option<VerificationIdentity>,
+    client_id: Option<String>,
+    organization_id: Option<String>,
+}
+
+impl CreateEmailVerificationTicketRequestBuilder {
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    pub fn result_url(mut self, result_url: impl Into<String>) -> Self {
+        self.result_url = Some(result_url.into());
+        self
+    }
+
+    pub fn ttl_sec(mut self, ttl_sec: i32) -> Self {
+        self.ttl_sec = Some(ttl_sec);
+        self
+    }
+
+    pub fn include_email_in_redirect(mut self, include: bool) -> Self {
+        self.include_email_in_redirect = Some(include);
+        self
+    }
+
+    pub fn identity(mut self, identity: VerificationIdentity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn organization_id(mut self, organization_id: impl Into<String>) -> Self {
+        self.organization_id = Some(organization_id.into());
+        self
+    }
+
+    pub fn build(self) -> Result<CreateEmailVerificationTicketRequest> {
+        let user_id = self
+            .user_id
+            .ok_or_else(|| Auth0Error::InvalidRequest("User ID is required".to_string()))?;
+
+        if let Some(ttl) = self.ttl_sec {
+            if ttl <= 0 {
+                return Err(Auth0Error::InvalidRequest(
+                    "TTL must be positive".to_string(),
+                ));
+            }
+        }
+
+        if let Some(ref identity) = self.identity {
+            identity.validate()?;
+        }
+
+        Ok(CreateEmailVerificationTicketRequest {
+            user_id,
+            result_url: self.result_url,
+            ttl_sec: self.ttl_sec,
+            include_email_in_redirect: self.include_email_in_redirect,
+            identity: self.identity,
+            client_id: self.client_id,
+            organization_id: self.organization_id,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateEmailVerificationTicketResponse {
+    /// The URL for the email verification ticket.
+    pub ticket: String,
+}
+
+/// Call the Auth0 Management API to create an email verification ticket.
+///
+/// * `domain` – The Auth0 domain (e.g. `my-tenant.eu.auth0.com`).
+/// * `token` – Bearer token with `create:user_tickets` scope.
+/// * `request` – Body describing the email verification ticket.
+pub async fn create_email_verification_ticket(
+    domain: &Domain,
+    token: &BearerToken,
+    request: CreateEmailVerificationTicketRequest,
+) -> Result<CreateEmailVerificationTicketResponse> {
+    let url = domain.to_url("/api/v2/tickets/email-verification");
+
+    let resp = Client::new()
+        .post(url)
+        .bearer_auth(token.as_str())
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await?;
+
+    if resp.status().is_success() {
+        let ticket = resp.json::<CreateEmailVerificationTicketResponse>().await?;
+        Ok(ticket)
+    } else {
+        Err(Auth0Error::from_response(resp).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[test]
+    fn test_create_email_verification_ticket_request_builder_valid() {
+        let req = CreateEmailVerificationTicketRequest::builder()
+            .user_id("auth0|507f1f77bcf86cd799439011")
+            .result_url("https://myapp.com/email-verified")
+            .ttl_sec(86400)
+            .include_email_in_redirect(true)
+            .identity(VerificationIdentity::new("1234567890", "google-oauth2"))
+            .build();
+
+        assert!(req.is_ok());
+        let req = req.unwrap();
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "user_id": "auth0|507f1f77bcf86cd799439011",
+                "result_url": "https://myapp.com/email-verified",
+                "ttl_sec": 86400,
+                "includeEmailInRedirect": true,
+                "identity": {"user_id": "1234567890", "provider": "google-oauth2"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_email_verification_ticket_request_builder_invalid() {
+        let missing_user = CreateEmailVerificationTicketRequest::builder().build();
+        assert!(matches!(missing_user, Err(Auth0Error::InvalidRequest(_))));
+
+        let bad_ttl = CreateEmailVerificationTicketRequest::builder()
+            .user_id("auth0|1")
+            .ttl_sec(0)
+            .build();
+        assert!(matches!(bad_ttl, Err(Auth0Error::InvalidRequest(_))));
+
+        let bad_identity = CreateEmailVerificationTicketRequest::builder()
+            .user_id("auth0|1")
+            .identity(VerificationIdentity::new("", "google-oauth2"))
+            .build();
+        assert!(matches!(bad_identity, Err(Auth0Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_email_verification_ticket_success() {
+        let mut server = Server::new_async().await;
+        let domain = Domain::new(server.url()).expect("Valid test domain");
+        let token = BearerToken::new("test-token").expect("Valid test token");
+
+        let mock = server
+            .mock("POST", "/api/v2/tickets/email-verification")
+            .match_header("Authorization", "Bearer test-token")
+            .match_body(mockito::Matcher::JsonString(
+                r#"{"user_id": "auth0|1"}"#.to_string(),
+            ))
+            .with_status(201)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{"ticket": "https://tenant.auth0.com/u/email-verification?ticket=abc"}"#)
+            .create_async()
+            .await;
+
+        let req = CreateEmailVerificationTicketRequest::builder()
+            .user_id("auth0|1")
+            .build()
+            .unwrap();
+        let result = create_email_verification_ticket(&domain, &token, req).await;
+        mock.assert_async().await;
+
+        assert!(result.unwrap().ticket.contains("ticket=abc"));
+    }
+}
@@ -44,6 +44,7 @@ pub struct CreateUserRequest {
     pub email: String,
 
     /// The connection to create the user in (e.g. "Username-Password-Authentication").
+    /// Valid names can be discovered with [`crate::connections::list_connections`].
     pub connection: String,
 
     /// The user's password, if applicable.
@@ -297,7 +298,7 @@ pub struct CreateUserResponse {
     pub identities: Vec<Identity>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Identity {
     /// The connection name.
     pub connection: String,
@@ -308,7 +309,8 @@ pub struct Identity {
     /// The identity provider.
     pub provider: String,
 
-    /// Whether this is a social identity.
+    /// Whether this is a social identity. Actions events name it `isSocial`.
+    #[serde(alias = "isSocial", skip_serializing_if = "Option::is_none")]
     pub is_social: Option<bool>,
 }
 